@@ -122,6 +122,7 @@ fn benchmark<'a>(
             &Theme::Dark,
             &core::renderer::Style {
                 text_color: Color::WHITE,
+                ..core::renderer::Style::default()
             },
             mouse::Cursor::Unavailable,
         );
@@ -0,0 +1,325 @@
+//! Animate values smoothly over time.
+use crate::time::{Duration, Instant};
+use crate::window::RedrawRequest;
+use crate::{Color, Point, Radians, Size, Vector};
+
+use std::sync::atomic::{self, AtomicBool};
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether animations should be skipped in favor of instant
+/// transitions.
+///
+/// The shell sets this on startup from its `reduced_motion` setting, so
+/// calling it directly is only needed to change the preference at runtime.
+pub fn set_reduced_motion(reduced_motion: bool) {
+    REDUCED_MOTION.store(reduced_motion, atomic::Ordering::Relaxed);
+}
+
+/// Returns whether animations should be skipped in favor of instant
+/// transitions.
+pub fn is_reduced_motion() -> bool {
+    REDUCED_MOTION.load(atomic::Ordering::Relaxed)
+}
+
+/// A value that transitions smoothly to a new state over time.
+///
+/// An [`Animation`] is driven by the current time; it does not schedule
+/// anything by itself. Use [`Animation::redraw_request`] to obtain the
+/// [`RedrawRequest`] needed to keep it moving from a widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    duration: Duration,
+    delay: Duration,
+    easing: Easing,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolable,
+{
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            started_at: None,
+            duration: Duration::from_millis(200),
+            delay: Duration::ZERO,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Duration`] of the transitions of the [`Animation`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the delay before the transitions of the [`Animation`] start.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the [`Easing`] of the transitions of the [`Animation`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Transitions the [`Animation`] to a new value, starting at the given
+    /// [`Instant`].
+    pub fn go(mut self, value: T, at: Instant) -> Self {
+        self.go_mut(value, at);
+        self
+    }
+
+    /// Transitions the [`Animation`] to a new value in place, starting at the
+    /// given [`Instant`].
+    ///
+    /// The transition starts from the current value of the [`Animation`],
+    /// so interrupting an ongoing transition does not cause any jumps.
+    pub fn go_mut(&mut self, value: T, at: Instant) {
+        if is_reduced_motion() {
+            self.from = value;
+            self.started_at = None;
+        } else {
+            self.from = self.value(at);
+            self.started_at = Some(at);
+        }
+
+        self.to = value;
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, at: Instant) -> T {
+        match self.progress(at) {
+            Some(progress) => self.from.interpolate(self.to, progress),
+            None => self.to,
+        }
+    }
+
+    /// Returns the final value of the [`Animation`].
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    /// Returns true if the [`Animation`] is still transitioning at the given
+    /// [`Instant`].
+    pub fn is_animating(&self, at: Instant) -> bool {
+        self.progress(at).is_some()
+    }
+
+    /// Returns the [`RedrawRequest`] needed to keep the [`Animation`] moving
+    /// at the given [`Instant`], if any.
+    pub fn redraw_request(&self, at: Instant) -> Option<RedrawRequest> {
+        let started_at = self.started_at?;
+        let start = started_at + self.delay;
+
+        if at < start {
+            Some(RedrawRequest::At(start))
+        } else if self.is_animating(at) {
+            Some(RedrawRequest::NextFrame)
+        } else {
+            None
+        }
+    }
+
+    fn progress(&self, at: Instant) -> Option<f32> {
+        let start = self.started_at? + self.delay;
+
+        if at < start {
+            return Some(0.0);
+        }
+
+        let elapsed = at - start;
+
+        if elapsed >= self.duration {
+            return None;
+        }
+
+        Some(
+            self.easing
+                .apply(elapsed.as_secs_f32() / self.duration.as_secs_f32()),
+        )
+    }
+}
+
+/// The curve followed by the transitions of an [`Animation`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slow and accelerates.
+    EaseIn,
+
+    /// Starts fast and decelerates.
+    EaseOut,
+
+    /// Starts slow, accelerates, and decelerates at the end.
+    #[default]
+    EaseInOut,
+
+    /// A damped spring that may overshoot its target before settling.
+    Spring {
+        /// How much the spring bounces, from `0.0` (no bounce) to `1.0`.
+        bounce: f32,
+    },
+}
+
+impl Easing {
+    /// Maps the linear progress of a transition, from `0.0` to `1.0`, to
+    /// its eased progress.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Spring { bounce } => {
+                let frequency =
+                    bounce.clamp(0.0, 1.0) * 3.0 * std::f32::consts::PI;
+
+                let spring =
+                    |t: f32| 1.0 - (-6.0 * t).exp() * (frequency * t).cos();
+
+                // The spring has not fully settled by the end, so we scale
+                // the curve to land exactly on the target
+                spring(t) / spring(1.0)
+            }
+        }
+    }
+}
+
+/// A value that can be interpolated by an [`Animation`].
+pub trait Interpolable: Copy {
+    /// Interpolates between `self` and `other` by the given `ratio`.
+    ///
+    /// A `ratio` of `0.0` produces `self` and a `ratio` of `1.0` produces
+    /// `other`. Ratios outside of this range are allowed, since some
+    /// [`Easing`] curves may overshoot.
+    fn interpolate(self, other: Self, ratio: f32) -> Self;
+}
+
+impl Interpolable for f32 {
+    fn interpolate(self, other: Self, ratio: f32) -> Self {
+        self + (other - self) * ratio
+    }
+}
+
+impl Interpolable for Radians {
+    fn interpolate(self, other: Self, ratio: f32) -> Self {
+        Radians(self.0.interpolate(other.0, ratio))
+    }
+}
+
+impl Interpolable for Point {
+    fn interpolate(self, other: Self, ratio: f32) -> Self {
+        Point::new(
+            self.x.interpolate(other.x, ratio),
+            self.y.interpolate(other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Vector {
+    fn interpolate(self, other: Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(other.x, ratio),
+            self.y.interpolate(other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Size {
+    fn interpolate(self, other: Self, ratio: f32) -> Self {
+        Size::new(
+            self.width.interpolate(other.width, ratio),
+            self.height.interpolate(other.height, ratio),
+        )
+    }
+}
+
+impl Interpolable for Color {
+    fn interpolate(self, other: Self, ratio: f32) -> Self {
+        Color {
+            r: self.r.interpolate(other.r, ratio),
+            g: self.g.interpolate(other.g, ratio),
+            b: self.b.interpolate(other.b, ratio),
+            a: self.a.interpolate(other.a, ratio),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition() {
+        let now = Instant::now();
+        let animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear)
+            .go(1.0, now);
+
+        assert_eq!(animation.value(now), 0.0);
+        assert_eq!(animation.value(now + Duration::from_millis(50)), 0.5);
+        assert_eq!(animation.value(now + Duration::from_millis(100)), 1.0);
+
+        assert!(animation.is_animating(now));
+        assert!(!animation.is_animating(now + Duration::from_millis(100)));
+
+        assert_eq!(
+            animation.redraw_request(now),
+            Some(RedrawRequest::NextFrame)
+        );
+        assert_eq!(
+            animation.redraw_request(now + Duration::from_millis(100)),
+            None
+        );
+    }
+
+    #[test]
+    fn delay() {
+        let now = Instant::now();
+        let animation = Animation::new(0.0)
+            .delay(Duration::from_millis(50))
+            .go(1.0, now);
+
+        assert_eq!(animation.value(now), 0.0);
+        assert_eq!(
+            animation.redraw_request(now),
+            Some(RedrawRequest::At(now + Duration::from_millis(50)))
+        );
+    }
+
+    #[test]
+    fn easing_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::Spring { bounce: 0.0 },
+            Easing::Spring { bounce: 0.5 },
+            Easing::Spring { bounce: 1.0 },
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+    }
+}
@@ -282,6 +282,10 @@ where
         self.widget.diff(tree);
     }
 
+    fn id(&self) -> Option<&widget::Id> {
+        self.widget.id()
+    }
+
     fn size(&self) -> Size<Length> {
         self.widget.size()
     }
@@ -426,6 +430,10 @@ where
         self.element.widget.diff(tree);
     }
 
+    fn id(&self) -> Option<&widget::Id> {
+        self.element.widget.id()
+    }
+
     fn layout(
         &self,
         tree: &mut Tree,
@@ -9,6 +9,7 @@ use crate::SmolStr;
 ///
 /// [open an issue]: https://github.com/iced-rs/iced/issues
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
@@ -29,6 +30,9 @@ pub enum Event {
 
         /// The text produced by the key press, if any.
         text: Option<SmolStr>,
+
+        /// Whether the key press was synthesized by auto-repeat.
+        repeat: bool,
     },
 
     /// A keyboard key was released.
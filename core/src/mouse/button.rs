@@ -1,5 +1,6 @@
 /// The button of a mouse.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Button {
     /// The left mouse button.
     Left,
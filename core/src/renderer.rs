@@ -91,12 +91,18 @@ impl Default for Quad {
 pub struct Style {
     /// The text color
     pub text_color: Color,
+    /// The accent color, if any
+    pub accent_color: Option<Color>,
+    /// The selection color, if any
+    pub selection_color: Option<Color>,
 }
 
 impl Default for Style {
     fn default() -> Self {
         Style {
             text_color: Color::BLACK,
+            accent_color: None,
+            selection_color: None,
         }
     }
 }
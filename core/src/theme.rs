@@ -4,6 +4,7 @@ pub mod palette;
 pub use palette::Palette;
 
 use std::fmt;
+use std::sync::atomic::{self, AtomicU8};
 use std::sync::Arc;
 
 /// A built-in theme.
@@ -164,21 +165,77 @@ impl Default for Theme {
     fn default() -> Self {
         #[cfg(feature = "auto-detect-theme")]
         {
-            use once_cell::sync::Lazy;
+            match Mode::system() {
+                Mode::Light => Theme::Light,
+                Mode::Dark => Theme::Dark,
+            }
+        }
+
+        #[cfg(not(feature = "auto-detect-theme"))]
+        Theme::Light
+    }
+}
+
+/// The light or dark preference of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// A light color scheme.
+    Light,
+    /// A dark color scheme.
+    Dark,
+}
 
-            static DEFAULT: Lazy<Theme> =
-                Lazy::new(|| match dark_light::detect() {
-                    dark_light::Mode::Dark => Theme::Dark,
+static SYSTEM_MODE: AtomicU8 = AtomicU8::new(0);
+
+impl Mode {
+    /// Returns the current [`Mode`] of the system.
+    ///
+    /// If the `auto-detect-theme` feature is enabled, the [`Mode`] is
+    /// detected the first time this is called; otherwise, it defaults to
+    /// [`Mode::Light`] until the runtime reports a change.
+    pub fn system() -> Self {
+        match SYSTEM_MODE.load(atomic::Ordering::Relaxed) {
+            1 => Mode::Light,
+            2 => Mode::Dark,
+            _ => {
+                #[cfg(feature = "auto-detect-theme")]
+                let mode = match dark_light::detect() {
+                    dark_light::Mode::Dark => Mode::Dark,
                     dark_light::Mode::Light | dark_light::Mode::Default => {
-                        Theme::Light
+                        Mode::Light
                     }
-                });
+                };
+
+                #[cfg(not(feature = "auto-detect-theme"))]
+                let mode = Mode::Light;
+
+                let _ = SYSTEM_MODE.compare_exchange(
+                    0,
+                    mode.to_u8(),
+                    atomic::Ordering::Relaxed,
+                    atomic::Ordering::Relaxed,
+                );
 
-            DEFAULT.clone()
+                mode
+            }
         }
+    }
 
-        #[cfg(not(feature = "auto-detect-theme"))]
-        Theme::Light
+    /// Changes the current [`Mode`] of the system.
+    ///
+    /// This is normally called by the runtime when the system preference
+    /// changes. If the `auto-detect-theme` feature is enabled, the
+    /// [`Default`] theme will follow it.
+    pub fn set_system(mode: Self) {
+        SYSTEM_MODE.store(mode.to_u8(), atomic::Ordering::Relaxed);
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Mode::Light => 1,
+            Mode::Dark => 2,
+        }
     }
 }
 
@@ -99,6 +99,14 @@ where
     /// Reconciles the [`Widget`] with the provided [`Tree`].
     fn diff(&self, _tree: &mut Tree) {}
 
+    /// Returns the [`Id`] of the [`Widget`], if any.
+    ///
+    /// The [`Id`] is used to keep the [`Tree`] of the [`Widget`] when its
+    /// siblings are reordered, inserted, or removed.
+    fn id(&self) -> Option<&Id> {
+        None
+    }
+
     /// Applies an [`Operation`] to the [`Widget`].
     fn operate(
         &self,
@@ -8,7 +8,7 @@ pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
 use crate::widget::Id;
-use crate::{Rectangle, Vector};
+use crate::{Point, Rectangle, Vector};
 
 use std::any::Any;
 use std::fmt;
@@ -410,6 +410,198 @@ where
     }
 }
 
+/// Produces an [`Operation`] that returns the [`Id`] of the innermost
+/// identified widget containing the given [`Point`], if any.
+///
+/// Only widgets that expose their bounds while operating (i.e. containers
+/// and scrollables) can be hit.
+pub fn hit_test(point: Point) -> impl Operation<Option<Id>> {
+    struct HitTest {
+        point: Point,
+        translation: Option<Vector>,
+        hit: Option<Id>,
+    }
+
+    impl Operation<Option<Id>> for HitTest {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<Id>>),
+        ) {
+            let translation = self.translation.take();
+
+            if !bounds.contains(self.point) {
+                return;
+            }
+
+            if let Some(id) = id {
+                self.hit = Some(id.clone());
+            }
+
+            // The children of a scrollable are laid out without
+            // its translation applied
+            let translation = translation.unwrap_or(Vector::ZERO);
+
+            self.point = self.point + translation;
+            operate_on_children(self);
+            self.point = self.point - translation;
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+        ) {
+            if bounds.contains(self.point) {
+                self.translation = Some(translation);
+            }
+        }
+
+        fn finish(&self) -> Outcome<Option<Id>> {
+            Outcome::Some(self.hit.clone())
+        }
+    }
+
+    HitTest {
+        point,
+        translation: None,
+        hit: None,
+    }
+}
+
+/// Produces an [`Operation`] that returns the bounds of the widget with the
+/// given [`Id`], if found.
+///
+/// The bounds are relative to the window, with the translation of any
+/// scrollables applied. However, they are not clipped to the viewport of
+/// those scrollables and, therefore, they may not be fully visible.
+///
+/// Only widgets that expose their bounds while operating (i.e. containers
+/// and scrollables) can be found.
+pub fn bounds(target: Id) -> impl Operation<Option<Rectangle>> {
+    struct Bounds {
+        target: Id,
+        translation: Vector,
+        scrollable_translation: Option<Vector>,
+        bounds: Option<Rectangle>,
+    }
+
+    impl Operation<Option<Rectangle>> for Bounds {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<Rectangle>>,
+            ),
+        ) {
+            let scrollable_translation =
+                self.scrollable_translation.take().unwrap_or(Vector::ZERO);
+
+            if self.bounds.is_some() {
+                return;
+            }
+
+            if id == Some(&self.target) {
+                self.bounds = Some(bounds - self.translation);
+                return;
+            }
+
+            self.translation = self.translation + scrollable_translation;
+            operate_on_children(self);
+            self.translation = self.translation - scrollable_translation;
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.scrollable_translation = Some(translation);
+        }
+
+        fn finish(&self) -> Outcome<Option<Rectangle>> {
+            Outcome::Some(self.bounds)
+        }
+    }
+
+    Bounds {
+        target,
+        translation: Vector::ZERO,
+        scrollable_translation: None,
+        bounds: None,
+    }
+}
+
+/// The bounds of a widget that ignores the mouse and touch interactions
+/// that start within them.
+///
+/// Widgets can report it while operating with [`Operation::custom`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PassThrough(pub Rectangle);
+
+/// Produces an [`Operation`] that collects the visible bounds of every
+/// [`PassThrough`] reported by the widgets.
+pub fn pass_through() -> impl Operation<Vec<Rectangle>> {
+    struct Collect {
+        translation: Vector,
+        scrollable_translation: Option<Vector>,
+        regions: Vec<Rectangle>,
+    }
+
+    impl Operation<Vec<Rectangle>> for Collect {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Vec<Rectangle>>,
+            ),
+        ) {
+            let scrollable_translation =
+                self.scrollable_translation.take().unwrap_or(Vector::ZERO);
+
+            self.translation = self.translation + scrollable_translation;
+            operate_on_children(self);
+            self.translation = self.translation - scrollable_translation;
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.scrollable_translation = Some(translation);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+            if let Some(PassThrough(bounds)) = state.downcast_ref() {
+                self.regions.push(*bounds - self.translation);
+            }
+        }
+
+        fn finish(&self) -> Outcome<Vec<Rectangle>> {
+            Outcome::Some(self.regions.clone())
+        }
+    }
+
+    Collect {
+        translation: Vector::ZERO,
+        scrollable_translation: None,
+        regions: Vec::new(),
+    }
+}
+
 /// Produces an [`Operation`] that applies the given [`Operation`] to the
 /// children of a container with the given [`Id`].
 pub fn scope<T: 'static>(
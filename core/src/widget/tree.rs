@@ -1,6 +1,8 @@
 //! Store internal widget state in a state tree to ensure continuity.
+use crate::widget::Id;
 use crate::Widget;
 
+use rustc_hash::FxHashMap;
 use std::any::{self, Any};
 use std::borrow::Borrow;
 use std::fmt;
@@ -13,6 +15,9 @@ pub struct Tree {
     /// The tag of the [`Tree`].
     pub tag: Tag,
 
+    /// The [`Id`] of the widget of the [`Tree`], if any.
+    pub id: Option<Id>,
+
     /// The [`State`] of the [`Tree`].
     pub state: State,
 
@@ -25,6 +30,7 @@ impl Tree {
     pub fn empty() -> Self {
         Self {
             tag: Tag::stateless(),
+            id: None,
             state: State::None,
             children: Vec::new(),
         }
@@ -41,6 +47,7 @@ impl Tree {
 
         Self {
             tag: widget.tag(),
+            id: widget.id().cloned(),
             state: widget.state(),
             children: widget.children(),
         }
@@ -61,6 +68,12 @@ impl Tree {
         Renderer: crate::Renderer,
     {
         if self.tag == new.borrow().tag() {
+            let id = new.borrow().id();
+
+            if self.id.as_ref() != id {
+                self.id = id.cloned();
+            }
+
             new.borrow().diff(self);
         } else {
             *self = Self::new(new);
@@ -68,12 +81,23 @@ impl Tree {
     }
 
     /// Reconciles the children of the tree with the provided list of widgets.
+    ///
+    /// The state of children with an [`Id`] is matched by [`Id`], so it is
+    /// kept even if their siblings change. The rest of children are matched
+    /// by position.
     pub fn diff_children<'a, Message, Theme, Renderer>(
         &mut self,
         new_children: &[impl Borrow<dyn Widget<Message, Theme, Renderer> + 'a>],
     ) where
         Renderer: crate::Renderer,
     {
+        if new_children
+            .iter()
+            .any(|child| child.borrow().id().is_some())
+        {
+            self.match_children_by_id(new_children);
+        }
+
         self.diff_children_custom(
             new_children,
             |tree, widget| tree.diff(widget.borrow()),
@@ -81,6 +105,48 @@ impl Tree {
         );
     }
 
+    /// Reorders the children of the tree so they line up with the provided
+    /// list of widgets, matching identified children by [`Id`] and the rest
+    /// by position.
+    fn match_children_by_id<'a, Message, Theme, Renderer>(
+        &mut self,
+        new_children: &[impl Borrow<dyn Widget<Message, Theme, Renderer> + 'a>],
+    ) where
+        Renderer: crate::Renderer,
+    {
+        // Built once, so matching stays linear for long keyed lists
+        let indices: FxHashMap<Id, usize> = self
+            .children
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, tree)| Some((tree.id.clone()?, index)))
+            .collect();
+
+        let mut current: Vec<Option<Tree>> =
+            self.children.drain(..).map(Some).collect();
+        let mut next_anonymous = 0;
+
+        self.children = new_children
+            .iter()
+            .map(|new| {
+                let new = new.borrow();
+
+                let tree = if let Some(id) = new.id() {
+                    indices.get(id).and_then(|index| current[*index].take())
+                } else {
+                    current[next_anonymous..].iter_mut().find_map(|tree| {
+                        next_anonymous += 1;
+
+                        tree.take_if(|tree| tree.id.is_none())
+                    })
+                };
+
+                tree.unwrap_or_else(|| Self::new(new))
+            })
+            .collect();
+    }
+
     /// Reconciles the children of the tree with the provided list of widgets using custom
     /// logic both for diffing and creating new widget state.
     pub fn diff_children_custom<T>(
@@ -267,3 +333,135 @@ impl fmt::Debug for State {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::layout;
+    use crate::mouse;
+    use crate::renderer;
+    use crate::{Layout, Length, Rectangle, Size};
+
+    struct Item {
+        id: Option<Id>,
+        name: &'static str,
+    }
+
+    fn item(id: &'static str) -> Box<dyn Widget<(), (), ()>> {
+        Box::new(Item {
+            id: Some(Id::new(id)),
+            name: id,
+        })
+    }
+
+    fn anonymous(name: &'static str) -> Box<dyn Widget<(), (), ()>> {
+        Box::new(Item { id: None, name })
+    }
+
+    impl Widget<(), (), ()> for Item {
+        fn tag(&self) -> Tag {
+            Tag::of::<&'static str>()
+        }
+
+        fn state(&self) -> State {
+            State::new(self.name)
+        }
+
+        fn id(&self) -> Option<&Id> {
+            self.id.as_ref()
+        }
+
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Shrink, Length::Shrink)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::ZERO)
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn tree(children: &[Box<dyn Widget<(), (), ()>>]) -> Tree {
+        let mut tree = Tree::empty();
+        tree.diff_children(children);
+        tree
+    }
+
+    /// Returns the name each child state was created with.
+    fn states(tree: &Tree) -> Vec<&'static str> {
+        tree.children
+            .iter()
+            .map(|child| *child.state.downcast_ref::<&'static str>())
+            .collect()
+    }
+
+    #[test]
+    fn reorder_keeps_state_by_id() {
+        let mut tree = tree(&[item("a"), item("b"), item("c")]);
+
+        tree.diff_children(&[item("c"), item("a"), item("b")]);
+
+        assert_eq!(states(&tree), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn insert_before_keeps_state_by_id() {
+        let mut tree = tree(&[item("a"), item("b")]);
+
+        tree.diff_children(&[item("x"), item("a"), item("b")]);
+
+        assert_eq!(states(&tree), ["x", "a", "b"]);
+    }
+
+    #[test]
+    fn removal_keeps_state_of_remaining_siblings() {
+        let mut tree = tree(&[item("a"), item("b"), item("c")]);
+
+        tree.diff_children(&[item("a"), item("c")]);
+
+        assert_eq!(states(&tree), ["a", "c"]);
+    }
+
+    #[test]
+    fn anonymous_siblings_are_matched_in_order() {
+        let mut tree =
+            tree(&[item("a"), anonymous("1"), item("b"), anonymous("2")]);
+
+        tree.diff_children(&[
+            item("b"),
+            anonymous("new"),
+            anonymous("new"),
+            item("a"),
+            anonymous("new"),
+        ]);
+
+        assert_eq!(states(&tree), ["b", "1", "2", "a", "new"]);
+    }
+
+    #[test]
+    fn positional_diff_updates_id() {
+        let mut tree = tree(&[item("a")]);
+
+        tree.diff_children(&[anonymous("new")]);
+
+        assert_eq!(tree.children[0].id, None);
+        assert_eq!(states(&tree), ["a"]);
+    }
+}
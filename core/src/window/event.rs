@@ -1,3 +1,4 @@
+use crate::theme;
 use crate::time::Instant;
 use crate::{Point, Size};
 
@@ -5,6 +6,7 @@ use std::path::PathBuf;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A window was opened.
     Opened {
@@ -31,6 +33,7 @@ pub enum Event {
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
+    #[cfg_attr(feature = "serde", serde(skip))]
     RedrawRequested(Instant),
 
     /// The user has requested for the window to close.
@@ -42,6 +45,22 @@ pub enum Event {
     /// A window was unfocused.
     Unfocused,
 
+    /// The application was suspended and the surface of the window was
+    /// destroyed.
+    ///
+    /// Nothing will be drawn until the window is [`Resumed`](Self::Resumed).
+    Suspended,
+
+    /// The application was resumed and the surface of the window was
+    /// recreated.
+    Resumed,
+
+    /// The light or dark preference of the system has changed.
+    ///
+    /// If the `auto-detect-theme` feature is enabled, the default theme
+    /// will follow the new preference automatically.
+    ThemeChanged(theme::Mode),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -0,0 +1,25 @@
+use crate::{Point, Size};
+
+/// The geometry of a window.
+///
+/// It can be captured and restored later to persist the layout of an
+/// application across sessions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geometry {
+    /// The size of the window, in logical pixels.
+    pub size: Size,
+
+    /// The position of the outer top-left corner of the window, in logical
+    /// pixels, if known.
+    pub position: Option<Point>,
+
+    /// Whether the window is maximized.
+    pub maximized: bool,
+
+    /// Whether the window is fullscreen.
+    pub fullscreen: bool,
+
+    /// The name of the monitor the window is on, if known.
+    pub monitor: Option<String>,
+}
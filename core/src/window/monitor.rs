@@ -0,0 +1,25 @@
+use crate::{Point, Size};
+
+/// Information about a monitor (also known as output) connected to the
+/// system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The human-readable name of the [`Monitor`], if available.
+    pub name: Option<String>,
+
+    /// The position of the top-left corner of the [`Monitor`] in the
+    /// desktop, in logical pixels.
+    pub position: Point,
+
+    /// The size of the [`Monitor`], in logical pixels.
+    pub size: Size,
+
+    /// The size of the [`Monitor`], in physical pixels.
+    pub physical_size: Size<u32>,
+
+    /// The scale factor of the [`Monitor`].
+    pub scale_factor: f32,
+
+    /// The refresh rate of the [`Monitor`], in millihertz, if available.
+    pub refresh_rate: Option<u32>,
+}
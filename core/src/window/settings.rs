@@ -73,6 +73,17 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// Whether the window will be hidden instead of closed when the user
+    /// requests it, e.g. when a user presses the close button.
+    ///
+    /// The program keeps running in the background and the window can be
+    /// shown again with the `window::show` command.
+    ///
+    /// This takes precedence over [`exit_on_close_request`](Self::exit_on_close_request).
+    ///
+    /// By default this is disabled.
+    pub hide_on_close_request: bool,
 }
 
 impl Default for Settings {
@@ -89,6 +100,7 @@ impl Default for Settings {
             level: Level::default(),
             icon: None,
             exit_on_close_request: true,
+            hide_on_close_request: false,
             platform_specific: PlatformSpecific::default(),
         }
     }
@@ -102,6 +102,7 @@ impl Gradient {
             application::Appearance {
                 background_color: Color::TRANSPARENT,
                 text_color: theme.palette().text,
+                ..Theme::default_style(theme)
             }
         } else {
             Theme::default_style(theme)
@@ -343,6 +343,7 @@ pub fn main() -> Result<(), winit::error::EventLoopError> {
                     &Theme::Dark,
                     &renderer::Style {
                         text_color: Color::WHITE,
+                        ..renderer::Style::default()
                     },
                     clipboard,
                     debug,
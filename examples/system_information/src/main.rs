@@ -119,6 +119,16 @@ impl Example {
                 let graphics_backend =
                     text!("Graphics backend: {}", information.graphics_backend);
 
+                let session_type = text!(
+                    "Session type: {}",
+                    information.session_type.as_deref().unwrap_or("unknown")
+                );
+
+                let compositor = text!(
+                    "Compositor: {}",
+                    information.compositor.as_deref().unwrap_or("unknown")
+                );
+
                 column![
                     system_name.size(30),
                     system_kernel.size(30),
@@ -130,6 +140,8 @@ impl Example {
                     memory_used.size(30),
                     graphics_adapter.size(30),
                     graphics_backend.size(30),
+                    session_type.size(30),
+                    compositor.size(30),
                     button("Refresh").on_press(Message::Refresh)
                 ]
                 .spacing(10)
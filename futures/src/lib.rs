@@ -17,6 +17,7 @@ pub mod executor;
 pub mod keyboard;
 pub mod stream;
 pub mod subscription;
+pub mod timer;
 
 pub use executor::Executor;
 pub use maybe::{MaybeSend, MaybeSync};
@@ -4,6 +4,7 @@ mod tracker;
 pub use tracker::Tracker;
 
 use crate::core::event;
+use crate::core::time::{Duration, Instant};
 use crate::core::window;
 use crate::futures::Stream;
 use crate::{BoxStream, MaybeSend};
@@ -284,6 +285,30 @@ impl<T> Subscription<T> {
                 .collect(),
         }
     }
+
+    /// Limits the rate of the [`Subscription`] output, producing at most one
+    /// value for every given interval.
+    ///
+    /// The first value is produced immediately. Any values produced by the
+    /// [`Subscription`] before the interval has elapsed are discarded, except
+    /// for the last one, which is produced once the interval ends.
+    ///
+    /// The interval will be part of the identity of the [`Subscription`].
+    pub fn throttle(mut self, interval: Duration) -> Self
+    where
+        T: 'static + MaybeSend,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Throttle::new(recipe, interval))
+                        as Box<dyn Recipe<Output = T>>
+                })
+                .collect(),
+        }
+    }
 }
 
 /// Creates a [`Subscription`] from a [`Recipe`] describing it.
@@ -416,6 +441,86 @@ where
     }
 }
 
+struct Throttle<A> {
+    recipe: Box<dyn Recipe<Output = A>>,
+    interval: Duration,
+}
+
+impl<A> Throttle<A> {
+    fn new(recipe: Box<dyn Recipe<Output = A>>, interval: Duration) -> Self {
+        Throttle { recipe, interval }
+    }
+}
+
+impl<A> Recipe for Throttle<A>
+where
+    A: 'static + MaybeSend,
+{
+    type Output = A;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.interval.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        use crate::timer;
+        use futures::stream;
+        use futures::{FutureExt, StreamExt};
+        use std::task::Poll;
+
+        let interval = self.interval;
+        let mut values = self.recipe.stream(input).fuse();
+        let mut last: Option<Instant> = None;
+        let mut trailing = None;
+        let mut deadline = None;
+
+        Box::pin(stream::poll_fn(move |cx| {
+            while let Poll::Ready(value) = values.poll_next_unpin(cx) {
+                let Some(value) = value else {
+                    return Poll::Ready(trailing.take());
+                };
+
+                let now = Instant::now();
+
+                match last {
+                    Some(last) if now.duration_since(last) < interval => {
+                        trailing = Some(value);
+
+                        if deadline.is_none() {
+                            deadline = Some(timer::sleep(
+                                interval - now.duration_since(last),
+                            ));
+                        }
+                    }
+                    _ => {
+                        last = Some(now);
+                        trailing = None;
+                        deadline = None;
+
+                        return Poll::Ready(Some(value));
+                    }
+                }
+            }
+
+            if let Some(timer) = &mut deadline {
+                if timer.poll_unpin(cx).is_ready() {
+                    deadline = None;
+
+                    if let Some(value) = trailing.take() {
+                        last = Some(Instant::now());
+
+                        return Poll::Ready(Some(value));
+                    }
+                }
+            }
+
+            Poll::Pending
+        }))
+    }
+}
+
 pub(crate) fn filter_map<I, F, T>(id: I, f: F) -> Subscription<T>
 where
     I: Hash + 'static,
@@ -459,3 +564,79 @@ where
         crate::boxed_stream((self.spawn)(input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    struct Values(Option<mpsc::UnboundedReceiver<u32>>);
+
+    impl Recipe for Values {
+        type Output = u32;
+
+        fn hash(&self, state: &mut Hasher) {
+            TypeId::of::<Self>().hash(state);
+        }
+
+        fn stream(
+            mut self: Box<Self>,
+            _input: EventStream,
+        ) -> BoxStream<Self::Output> {
+            Box::pin(self.0.take().expect("stream is only taken once"))
+        }
+    }
+
+    #[test]
+    fn throttle_produces_leading_and_trailing_values() {
+        let interval = Duration::from_millis(20);
+        let (sender, receiver) = mpsc::unbounded();
+
+        let subscription =
+            from_recipe(Values(Some(receiver))).throttle(interval);
+
+        let mut recipes = into_recipes(subscription);
+        let recipe = recipes.pop().expect("subscription has a recipe");
+        let mut values =
+            recipe.stream(Box::pin(futures::stream::pending::<Event>()));
+
+        let start = Instant::now();
+
+        for value in 1..=3 {
+            sender.unbounded_send(value).unwrap();
+        }
+
+        assert_eq!(block_on(values.next()), Some(1));
+        assert_eq!(block_on(values.next()), Some(3));
+        assert!(start.elapsed() >= interval);
+
+        drop(sender);
+
+        assert_eq!(block_on(values.next()), None);
+    }
+
+    #[test]
+    fn throttle_produces_trailing_value_when_finished() {
+        let interval = Duration::from_secs(60);
+        let (sender, receiver) = mpsc::unbounded();
+
+        let subscription =
+            from_recipe(Values(Some(receiver))).throttle(interval);
+
+        let mut recipes = into_recipes(subscription);
+        let recipe = recipes.pop().expect("subscription has a recipe");
+        let values =
+            recipe.stream(Box::pin(futures::stream::pending::<Event>()));
+
+        for value in 1..=3 {
+            sender.unbounded_send(value).unwrap();
+        }
+
+        drop(sender);
+
+        assert_eq!(block_on(values.collect::<Vec<_>>()), vec![1, 3]);
+    }
+}
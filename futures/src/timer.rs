@@ -0,0 +1,23 @@
+//! Wait for some time to pass, regardless of the executor.
+use crate::core::time::Duration;
+
+use futures::Future;
+
+/// Returns a [`Future`] that completes once the given `duration` has passed.
+///
+/// Unlike the `time` modules of each backend, the timer is driven by its own
+/// thread and, therefore, it can be awaited in any executor.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + Unpin {
+    use futures::FutureExt;
+
+    async_io::Timer::after(duration).map(|_| ())
+}
+
+/// Returns a [`Future`] that completes once the given `duration` has passed.
+#[cfg(target_arch = "wasm32")]
+pub fn sleep(duration: Duration) -> impl Future<Output = ()> + Unpin {
+    use futures::FutureExt;
+
+    wasm_timer::Delay::new(duration).map(|_| ())
+}
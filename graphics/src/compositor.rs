@@ -59,6 +59,18 @@ pub trait Compositor: Sized {
         height: u32,
     );
 
+    /// Destroys a [`Self::Renderer`] and its [`Surface`], eagerly releasing
+    /// any resources they hold.
+    ///
+    /// This is called when a window is closed. By default, they are simply
+    /// dropped.
+    ///
+    /// [`Surface`]: Self::Surface
+    fn destroy(&mut self, renderer: Self::Renderer, surface: Self::Surface) {
+        drop(surface);
+        drop(renderer);
+    }
+
     /// Returns [`Information`] used by this [`Compositor`].
     fn fetch_information(&self) -> Information;
 
@@ -95,6 +107,32 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Vec<u8>;
+
+    /// Screenshots the current [`Renderer`] primitives like [`screenshot`],
+    /// but writes the bytes into the given buffer, reusing its allocation.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    /// [`screenshot`]: Self::screenshot
+    fn screenshot_into<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+        buffer: &mut Vec<u8>,
+    ) {
+        let bytes = self.screenshot(
+            renderer,
+            surface,
+            viewport,
+            background_color,
+            overlay,
+        );
+
+        buffer.clear();
+        buffer.extend_from_slice(&bytes);
+    }
 }
 
 /// A window that can be used in a [`Compositor`].
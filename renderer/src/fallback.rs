@@ -307,6 +307,26 @@ where
         }
     }
 
+    fn destroy(&mut self, renderer: Self::Renderer, surface: Self::Surface) {
+        match (self, renderer, surface) {
+            (
+                Self::Primary(compositor),
+                Renderer::Primary(renderer),
+                Surface::Primary(surface),
+            ) => {
+                compositor.destroy(renderer, surface);
+            }
+            (
+                Self::Secondary(compositor),
+                Renderer::Secondary(renderer),
+                Surface::Secondary(surface),
+            ) => {
+                compositor.destroy(renderer, surface);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn load_font(&mut self, font: Cow<'static, [u8]>) {
         delegate!(self, compositor, compositor.load_font(font));
     }
@@ -384,6 +404,44 @@ where
             _ => unreachable!(),
         }
     }
+
+    fn screenshot_into<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &graphics::Viewport,
+        background_color: Color,
+        overlay: &[T],
+        buffer: &mut Vec<u8>,
+    ) {
+        match (self, renderer, surface) {
+            (
+                Self::Primary(compositor),
+                Renderer::Primary(renderer),
+                Surface::Primary(surface),
+            ) => compositor.screenshot_into(
+                renderer,
+                surface,
+                viewport,
+                background_color,
+                overlay,
+                buffer,
+            ),
+            (
+                Self::Secondary(compositor),
+                Renderer::Secondary(renderer),
+                Surface::Secondary(surface),
+            ) => compositor.screenshot_into(
+                renderer,
+                surface,
+                viewport,
+                background_color,
+                overlay,
+                buffer,
+            ),
+            _ => unreachable!(),
+        }
+    }
 }
 
 #[cfg(feature = "wgpu")]
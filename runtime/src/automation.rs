@@ -0,0 +1,453 @@
+//! Record user interactions and replay them.
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::widget;
+use crate::core::window;
+use crate::core::{Event, Point, Rectangle, SmolStr, Vector};
+use crate::futures::MaybeSend;
+use crate::task::{self, Task};
+
+/// A user interaction with a window.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Interaction {
+    /// The widget with the given [`widget::Id`] was clicked.
+    ///
+    /// The identified widget may contain the widget that was actually
+    /// clicked, since only some widgets can be identified. Therefore, the
+    /// position of the click inside of it is kept as well.
+    Click {
+        /// The window of the widget.
+        window: window::Id,
+        /// The [`widget::Id`] of the clicked widget.
+        target: widget::Id,
+        /// The position of the click relative to the top-left corner of
+        /// the clicked widget.
+        offset: Vector,
+    },
+
+    /// Some text was typed.
+    Type {
+        /// The window that received the text.
+        window: window::Id,
+        /// The typed text.
+        text: String,
+    },
+
+    /// An [`Event`] that could not be described in terms of widgets.
+    Event {
+        /// The window that received the [`Event`].
+        window: window::Id,
+        /// The received [`Event`].
+        event: Event,
+    },
+}
+
+/// Describes the events received by a window as a list of [`Interaction`]s.
+#[derive(Debug)]
+pub struct Recorder {
+    window: window::Id,
+    interactions: Vec<Interaction>,
+    cursor: Option<Point>,
+    press: Press,
+}
+
+#[derive(Debug)]
+enum Press {
+    None,
+    Click(widget::Id, Point, Vector),
+    Raw,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] for the window with the given [`window::Id`].
+    pub fn new(window: window::Id) -> Self {
+        Self {
+            window,
+            interactions: Vec::new(),
+            cursor: None,
+            press: Press::None,
+        }
+    }
+
+    /// Records an [`Event`] received by the window of the [`Recorder`].
+    ///
+    /// The `hit_test` closure must return the [`widget::Id`] and the bounds
+    /// of the innermost identified widget under the given [`Point`], if any.
+    /// It is used to describe clicks in terms of the clicked widget.
+    pub fn record(
+        &mut self,
+        event: &Event,
+        hit_test: impl FnOnce(Point) -> Option<(widget::Id, Rectangle)>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                self.cursor = Some(*position);
+
+                match self.press {
+                    Press::None => {}
+                    Press::Click(_, origin, _) => {
+                        // The click became a drag, which cannot be
+                        // described in terms of widgets
+                        self.push(Event::Mouse(mouse::Event::CursorMoved {
+                            position: origin,
+                        }));
+                        self.push(Event::Mouse(mouse::Event::ButtonPressed(
+                            mouse::Button::Left,
+                        )));
+                        self.push(event.clone());
+
+                        self.press = Press::Raw;
+                    }
+                    Press::Raw => {
+                        self.push(event.clone());
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if matches!(self.press, Press::None) =>
+            {
+                if let Some(position) = self.cursor {
+                    if let Some((target, bounds)) = hit_test(position) {
+                        self.press = Press::Click(
+                            target,
+                            position,
+                            position - bounds.position(),
+                        );
+                        return;
+                    }
+                }
+
+                self.push_at_cursor(event.clone());
+                self.press = Press::Raw;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                match std::mem::replace(&mut self.press, Press::None) {
+                    Press::Click(target, _, offset) => {
+                        self.interactions.push(Interaction::Click {
+                            window: self.window,
+                            target,
+                            offset,
+                        });
+                    }
+                    Press::None | Press::Raw => {
+                        self.push(event.clone());
+                    }
+                }
+            }
+            Event::Mouse(
+                mouse::Event::CursorEntered | mouse::Event::CursorLeft,
+            ) => {
+                if matches!(self.press, Press::Raw) {
+                    self.push(event.clone());
+                }
+            }
+            Event::Mouse(_) => {
+                self.push_at_cursor(event.clone());
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                text: Some(text),
+                modifiers,
+                ..
+            }) if is_typing(text, *modifiers) => {
+                if let Some(Interaction::Type {
+                    window,
+                    text: typed,
+                }) = self.interactions.last_mut()
+                {
+                    if *window == self.window {
+                        typed.push_str(text);
+                        return;
+                    }
+                }
+
+                self.interactions.push(Interaction::Type {
+                    window: self.window,
+                    text: text.to_string(),
+                });
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Character(_),
+                modifiers,
+                ..
+            }) if !has_shortcut_modifiers(*modifiers) => {}
+            Event::Keyboard(_) | Event::Touch(_) => {
+                self.push(event.clone());
+            }
+            Event::Window(_) => {}
+        }
+    }
+
+    /// Finishes the recording and returns the recorded [`Interaction`]s.
+    pub fn finish(self) -> Vec<Interaction> {
+        self.interactions
+    }
+
+    fn push(&mut self, event: Event) {
+        self.interactions.push(Interaction::Event {
+            window: self.window,
+            event,
+        });
+    }
+
+    fn push_at_cursor(&mut self, event: Event) {
+        if let (Press::None, Some(position)) = (&self.press, self.cursor) {
+            self.push(Event::Mouse(mouse::Event::CursorMoved { position }));
+        }
+
+        self.push(event);
+    }
+}
+
+fn is_typing(text: &str, modifiers: keyboard::Modifiers) -> bool {
+    !has_shortcut_modifiers(modifiers) && !text.chars().any(char::is_control)
+}
+
+fn has_shortcut_modifiers(modifiers: keyboard::Modifiers) -> bool {
+    modifiers.control() || modifiers.alt() || modifiers.logo()
+}
+
+/// Starts recording the [`Interaction`]s of the window with the given
+/// [`window::Id`], discarding any ongoing recording.
+pub fn start_recording<T>(window: window::Id) -> Task<T> {
+    task::effect(crate::Action::Window(
+        crate::window::Action::StartRecording(window),
+    ))
+}
+
+/// Stops recording the [`Interaction`]s of the window with the given
+/// [`window::Id`] and produces them.
+pub fn stop_recording(window: window::Id) -> Task<Vec<Interaction>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(crate::window::Action::StopRecording(
+            window, channel,
+        ))
+    })
+}
+
+/// Replays the given [`Interaction`]s in order, as fast as possible.
+///
+/// Clicks target the current bounds of their widget, so they keep working
+/// even if the widget has moved since the recording. Clicks on widgets
+/// that cannot be found are skipped.
+pub fn replay<T>(interactions: impl IntoIterator<Item = Interaction>) -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    interactions
+        .into_iter()
+        .fold(Task::none(), |task, interaction| {
+            task.chain(play(interaction))
+        })
+}
+
+fn play<T>(interaction: Interaction) -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    match interaction {
+        Interaction::Click {
+            window,
+            target,
+            offset,
+        } => crate::window::operate(window, widget::operation::bounds(target))
+            .then(move |bounds| {
+                let Some(bounds) = bounds else {
+                    return Task::none();
+                };
+
+                dispatch(
+                    window,
+                    [
+                        Event::Mouse(mouse::Event::CursorMoved {
+                            position: bounds.position() + offset,
+                        }),
+                        Event::Mouse(mouse::Event::ButtonPressed(
+                            mouse::Button::Left,
+                        )),
+                        Event::Mouse(mouse::Event::ButtonReleased(
+                            mouse::Button::Left,
+                        )),
+                    ],
+                )
+            }),
+        Interaction::Type { window, text } => {
+            dispatch(window, text.chars().flat_map(type_character))
+        }
+        Interaction::Event { window, event } => dispatch(window, [event]),
+    }
+}
+
+pub(crate) fn type_character(character: char) -> [Event; 2] {
+    let text = SmolStr::new(character.encode_utf8(&mut [0; 4]));
+    let key = keyboard::Key::Character(text.clone());
+    let physical_key = keyboard::key::Physical::Unidentified(
+        keyboard::key::NativeCode::Unidentified,
+    );
+
+    [
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: Some(text),
+            repeat: false,
+        }),
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key: key.clone(),
+            modified_key: key,
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+        }),
+    ]
+}
+
+fn dispatch<T>(
+    window: window::Id,
+    events: impl IntoIterator<Item = Event>,
+) -> Task<T> {
+    task::effects(events.into_iter().map(|event| {
+        crate::Action::Window(crate::window::Action::DispatchEvent(
+            window, event,
+        ))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Size;
+
+    fn moved(x: f32, y: f32) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(x, y),
+        })
+    }
+
+    fn pressed() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn released() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn raw(window: window::Id, event: Event) -> Interaction {
+        Interaction::Event { window, event }
+    }
+
+    #[test]
+    fn click_on_widget() {
+        let window = window::Id::unique();
+        let target = widget::Id::new("main");
+        let bounds =
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(50.0, 50.0));
+        let mut recorder = Recorder::new(window);
+
+        // The click lands on a widget inside of the identified container
+        for event in [moved(15.0, 10.0), pressed(), released()] {
+            recorder.record(&event, |_| Some((target.clone(), bounds)));
+        }
+
+        assert_eq!(
+            recorder.finish(),
+            [Interaction::Click {
+                window,
+                target,
+                offset: Vector::new(10.0, 5.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn click_outside_widgets() {
+        let window = window::Id::unique();
+        let mut recorder = Recorder::new(window);
+
+        for event in [moved(10.0, 10.0), pressed(), released()] {
+            recorder.record(&event, |_| None);
+        }
+
+        assert_eq!(
+            recorder.finish(),
+            [
+                raw(window, moved(10.0, 10.0)),
+                raw(window, pressed()),
+                raw(window, released()),
+            ]
+        );
+    }
+
+    #[test]
+    fn drag_is_recorded_as_events() {
+        let window = window::Id::unique();
+        let target = widget::Id::new("slider");
+        let mut recorder = Recorder::new(window);
+
+        for event in [
+            moved(10.0, 10.0),
+            pressed(),
+            moved(20.0, 10.0),
+            moved(30.0, 10.0),
+            released(),
+        ] {
+            recorder.record(&event, |_| {
+                Some((target.clone(), Rectangle::with_size(Size::INFINITY)))
+            });
+        }
+
+        assert_eq!(
+            recorder.finish(),
+            [
+                raw(window, moved(10.0, 10.0)),
+                raw(window, pressed()),
+                raw(window, moved(20.0, 10.0)),
+                raw(window, moved(30.0, 10.0)),
+                raw(window, released()),
+            ]
+        );
+    }
+
+    #[test]
+    fn typing_is_merged() {
+        let window = window::Id::unique();
+        let mut recorder = Recorder::new(window);
+
+        for event in "hi".chars().flat_map(type_character) {
+            recorder.record(&event, |_| None);
+        }
+
+        assert_eq!(
+            recorder.finish(),
+            [Interaction::Type {
+                window,
+                text: String::from("hi"),
+            }]
+        );
+    }
+
+    #[test]
+    fn shortcuts_are_not_typing() {
+        let window = window::Id::unique();
+        let mut recorder = Recorder::new(window);
+
+        let shortcut = Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(SmolStr::new("c")),
+            modified_key: keyboard::Key::Character(SmolStr::new("c")),
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::CTRL,
+            text: Some(SmolStr::new("c")),
+            repeat: false,
+        });
+
+        recorder.record(&shortcut, |_| None);
+
+        assert_eq!(recorder.finish(), [raw(window, shortcut)]);
+    }
+}
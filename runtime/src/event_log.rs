@@ -0,0 +1,173 @@
+//! Record events to a compact binary log and replay them.
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::Event;
+use crate::task::{self, Task};
+
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+/// An [`Event`] recorded in an event log.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// The time elapsed since the recording started.
+    pub timestamp: Duration,
+
+    /// The [`window::Id`] of the window that received the [`Event`].
+    pub window: window::Id,
+
+    /// The recorded [`Event`].
+    pub event: Event,
+}
+
+/// Records events into an event log.
+#[derive(Debug)]
+pub struct Recorder<W = io::BufWriter<fs::File>> {
+    writer: W,
+    started_at: Instant,
+}
+
+impl Recorder {
+    /// Creates a [`Recorder`] that writes to the file at the given path,
+    /// replacing its contents.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = fs::File::create(path)?;
+
+        Ok(Self::new(io::BufWriter::new(file)))
+    }
+}
+
+impl<W> Recorder<W>
+where
+    W: io::Write,
+{
+    /// Creates a [`Recorder`] that writes to the given writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Records an [`Event`] received by the given window.
+    ///
+    /// Redraw requests are not recorded, since they are produced by the
+    /// runtime itself.
+    pub fn record(
+        &mut self,
+        window: window::Id,
+        event: &Event,
+    ) -> Result<(), Error> {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            return Ok(());
+        }
+
+        let entry = Entry {
+            timestamp: self.started_at.elapsed(),
+            window,
+            event: event.clone(),
+        };
+
+        bincode::serialize_into(&mut self.writer, &entry)?;
+
+        Ok(())
+    }
+
+    /// Flushes the recorded events to the underlying writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Reads all the entries of the event log at the given path.
+pub fn read(path: impl AsRef<Path>) -> Result<Vec<Entry>, Error> {
+    let mut reader = io::BufReader::new(fs::File::open(path)?);
+    let mut entries = Vec::new();
+
+    while !reader.fill_buf()?.is_empty() {
+        entries.push(bincode::deserialize_from(&mut reader)?);
+    }
+
+    Ok(entries)
+}
+
+/// Replays the given entries of an event log, dispatching each [`Event`]
+/// to its window in order.
+///
+/// The events are dispatched right away. The timestamps of the entries can
+/// be used to pace them, if needed.
+///
+/// Entries are dispatched to the [`window::Id`] they were recorded with.
+/// Since these ids are assigned in the order windows are opened, the
+/// replaying session must open its windows in the same order as the
+/// recorded one; events for windows that do not exist are discarded.
+/// The [`Entry::window`] field can be remapped before replaying otherwise.
+pub fn replay<T>(entries: impl IntoIterator<Item = Entry>) -> Task<T> {
+    task::effects(entries.into_iter().map(|entry| {
+        crate::Action::Window(crate::window::Action::DispatchEvent(
+            entry.window,
+            entry.event,
+        ))
+    }))
+}
+
+/// An error produced while recording or reading an event log.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The event log could not be accessed.
+    #[error("the event log could not be accessed: {0}")]
+    Io(#[from] io::Error),
+
+    /// An entry of the event log could not be encoded or decoded.
+    #[error("an entry of the event log is invalid: {0}")]
+    Encoding(#[from] bincode::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::keyboard;
+    use crate::core::mouse;
+    use crate::core::Point;
+
+    #[test]
+    fn record_and_read() {
+        let path = std::env::temp_dir()
+            .join(format!("iced-event-log-{}.bin", std::process::id()));
+
+        let window = window::Id::unique();
+        let events = [
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(10.0, 20.0),
+            }),
+            Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::SHIFT,
+            )),
+        ];
+
+        let mut recorder = Recorder::create(&path).unwrap();
+
+        for event in &events {
+            recorder.record(window, event).unwrap();
+        }
+
+        recorder.flush().unwrap();
+        drop(recorder);
+
+        let entries = read(&path);
+        let _ = fs::remove_file(&path);
+        let entries = entries.unwrap();
+
+        // Redraw requests are skipped
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| entry.window == window));
+        assert_eq!(entries[0].event, events[0]);
+        assert_eq!(entries[1].event, events[2]);
+        assert!(entries[0].timestamp <= entries[1].timestamp);
+    }
+}
@@ -9,6 +9,7 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod automation;
 pub mod clipboard;
 pub mod font;
 pub mod keyboard;
@@ -22,6 +23,9 @@ pub mod window;
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
+#[cfg(feature = "event-log")]
+pub mod event_log;
+
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
 #[cfg(feature = "debug")]
@@ -6,6 +6,8 @@ use iced_core::Element;
 
 mod state;
 
+pub mod test;
+
 pub use state::State;
 
 /// The core of a user interface application following The Elm Architecture.
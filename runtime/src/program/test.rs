@@ -0,0 +1,365 @@
+//! Drive a [`Program`] headlessly, without a windowing shell or a compositor.
+use crate::automation;
+use crate::core::clipboard;
+use crate::core::event::Event;
+use crate::core::mouse;
+use crate::core::widget::{self, operation, Operation};
+use crate::core::{Point, Rectangle, Size};
+use crate::user_interface::{self, UserInterface};
+use crate::{Program, Task};
+
+/// A headless simulator of a [`Program`].
+///
+/// A [`Simulator`] builds the widgets of a [`Program`] and feeds them
+/// synthetic events, producing the messages a real shell would obtain.
+/// It never draws anything, so it can be used with a null renderer (i.e. `()`).
+#[allow(missing_debug_implementations)]
+pub struct Simulator<P>
+where
+    P: Program + 'static,
+{
+    program: P,
+    renderer: P::Renderer,
+    size: Size,
+    cursor: mouse::Cursor,
+    cache: Option<user_interface::Cache>,
+}
+
+impl<P> Simulator<P>
+where
+    P: Program + 'static,
+{
+    /// Creates a new [`Simulator`] of the given [`Program`], laying out its
+    /// widgets with the provided renderer and logical size.
+    pub fn new(program: P, renderer: P::Renderer, size: Size) -> Self {
+        Self {
+            program,
+            renderer,
+            size,
+            cursor: mouse::Cursor::Unavailable,
+            cache: Some(user_interface::Cache::default()),
+        }
+    }
+
+    /// Returns a reference to the [`Program`] of the [`Simulator`].
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns the current [`mouse::Cursor`] of the [`Simulator`].
+    pub fn cursor(&self) -> mouse::Cursor {
+        self.cursor
+    }
+
+    /// Changes the logical size of the [`Simulator`].
+    pub fn resize(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    /// Processes the given events in order and returns the messages produced
+    /// by the widgets.
+    ///
+    /// The messages are not applied to the [`Program`]; use [`update`] for
+    /// that.
+    ///
+    /// [`update`]: Self::update
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<P::Message> {
+        let mut messages = Vec::new();
+
+        for event in events {
+            match &event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    self.cursor = mouse::Cursor::Available(*position);
+                }
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    self.cursor = mouse::Cursor::Unavailable;
+                }
+                _ => {}
+            }
+
+            let cursor = self.cursor;
+
+            self.with_user_interface(|user_interface, renderer| {
+                let _ = user_interface.update(
+                    &[event],
+                    cursor,
+                    renderer,
+                    &mut clipboard::Null,
+                    &mut messages,
+                );
+            });
+        }
+
+        messages
+    }
+
+    /// Handles a message with the [`Program`] of the [`Simulator`].
+    ///
+    /// The returned [`Task`] is not executed.
+    pub fn update(&mut self, message: P::Message) -> Task<P::Message> {
+        self.program.update(message)
+    }
+
+    /// Clicks the center of the widget with the given [`widget::Id`] and
+    /// returns the produced messages.
+    ///
+    /// The widget is located with [`find`], so the same limitations apply.
+    ///
+    /// [`find`]: Self::find
+    pub fn click(
+        &mut self,
+        target: impl Into<widget::Id>,
+    ) -> Result<Vec<P::Message>, Error> {
+        let target = target.into();
+
+        let Some(bounds) = self.find(target.clone()) else {
+            return Err(Error::NotFound(target));
+        };
+
+        Ok(self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: bounds.center(),
+            }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]))
+    }
+
+    /// Types the given text into the focused widget and returns the produced
+    /// messages.
+    pub fn typewrite(&mut self, text: &str) -> Vec<P::Message> {
+        self.simulate(text.chars().flat_map(automation::type_character))
+    }
+
+    /// Returns the visible bounds of the widget with the given
+    /// [`widget::Id`], if found.
+    ///
+    /// Only widgets that expose their bounds while operating (i.e. containers
+    /// and scrollables) can be found. Other widgets, like buttons or text
+    /// inputs, can be targeted by wrapping them in a container with an id.
+    pub fn find(&mut self, target: impl Into<widget::Id>) -> Option<Rectangle> {
+        let mut operation = operation::bounds(target.into());
+
+        self.operate(&mut operation::black_box(&mut operation));
+
+        match operation.finish() {
+            operation::Outcome::Some(bounds) => bounds,
+            _ => None,
+        }
+    }
+
+    /// Returns the [`widget::Id`] of the innermost identified widget under
+    /// the given [`Point`], if any.
+    pub fn hit_test(&mut self, point: Point) -> Option<widget::Id> {
+        self.with_user_interface(|user_interface, renderer| {
+            user_interface.hit_test(renderer, point)
+        })
+    }
+
+    /// Applies an [`Operation`] to the widgets of the [`Simulator`].
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        self.with_user_interface(|user_interface, renderer| {
+            user_interface.operate(renderer, operation);
+        });
+    }
+
+    fn with_user_interface<T>(
+        &mut self,
+        f: impl FnOnce(
+            &mut UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+            &mut P::Renderer,
+        ) -> T,
+    ) -> T {
+        let mut user_interface = UserInterface::build(
+            self.program.view(),
+            self.size,
+            self.cache.take().unwrap(),
+            &mut self.renderer,
+        );
+
+        let result = f(&mut user_interface, &mut self.renderer);
+
+        self.cache = Some(user_interface.into_cache());
+
+        result
+    }
+}
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// No widget with the given [`widget::Id`] was found.
+    #[error("no widget with id {0:?} was found")]
+    NotFound(widget::Id),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::layout::{self, Layout};
+    use crate::core::renderer;
+    use crate::core::widget::Tree;
+    use crate::core::{self, Clipboard, Element, Length, Shell, Widget};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Increment,
+    }
+
+    struct Counter {
+        value: i32,
+    }
+
+    impl Program for Counter {
+        type Renderer = ();
+        type Theme = ();
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Task<Message> {
+            match message {
+                Message::Increment => self.value += 1,
+            }
+
+            Task::none()
+        }
+
+        fn view(&self) -> Element<'_, Message, (), ()> {
+            Element::new(Pad {
+                id: widget::Id::new("pad"),
+            })
+        }
+    }
+
+    /// A widget that produces [`Message::Increment`] when clicked.
+    struct Pad {
+        id: widget::Id,
+    }
+
+    impl Widget<Message, (), ()> for Pad {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fixed(100.0), Length::Fixed(50.0))
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::new(100.0, 50.0))
+                .move_to(Point::new(10.0, 10.0))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn operate(
+            &self,
+            _tree: &mut Tree,
+            layout: Layout<'_>,
+            _renderer: &(),
+            operation: &mut dyn Operation,
+        ) {
+            operation.container(Some(&self.id), layout.bounds(), &mut |_| {});
+        }
+
+        fn on_event(
+            &mut self,
+            _tree: &mut Tree,
+            event: Event,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            _renderer: &(),
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+            _viewport: &Rectangle,
+        ) -> core::event::Status {
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) if cursor.is_over(layout.bounds()) => {
+                    shell.publish(Message::Increment);
+
+                    core::event::Status::Captured
+                }
+                _ => core::event::Status::Ignored,
+            }
+        }
+    }
+
+    fn simulator() -> Simulator<Counter> {
+        Simulator::new(Counter { value: 0 }, (), Size::new(200.0, 200.0))
+    }
+
+    #[test]
+    fn simulate_and_update() {
+        let mut simulator = simulator();
+
+        let messages = simulator.simulate([
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(20.0, 20.0),
+            }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(150.0, 150.0),
+            }),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        assert_eq!(messages, [Message::Increment]);
+        assert_eq!(
+            simulator.cursor(),
+            mouse::Cursor::Available(Point::new(150.0, 150.0))
+        );
+
+        for message in messages {
+            let _ = simulator.update(message);
+        }
+
+        assert_eq!(simulator.program().value, 1);
+    }
+
+    #[test]
+    fn click_by_id() {
+        let mut simulator = simulator();
+
+        assert_eq!(
+            simulator.find(widget::Id::new("pad")),
+            Some(Rectangle::new(
+                Point::new(10.0, 10.0),
+                Size::new(100.0, 50.0)
+            ))
+        );
+        assert_eq!(
+            simulator.click(widget::Id::new("pad")),
+            Ok(vec![Message::Increment])
+        );
+        assert_eq!(
+            simulator.cursor(),
+            mouse::Cursor::Available(Point::new(60.0, 35.0))
+        );
+
+        assert_eq!(
+            simulator.click(widget::Id::new("missing")),
+            Err(Error::NotFound(widget::Id::new("missing")))
+        );
+
+        let _ = simulator.update(Message::Increment);
+
+        assert_eq!(simulator.program().value, 1);
+    }
+}
@@ -1,5 +1,9 @@
 //! Access the native system.
+use crate::core::theme;
+use crate::core::window;
+use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
+use crate::futures::Subscription;
 
 /// An operation to be performed on the system.
 #[derive(Debug)]
@@ -36,4 +40,35 @@ pub struct Information {
     pub graphics_backend: String,
     /// Model information for the active graphics adapter
     pub graphics_adapter: String,
+    /// The kind of windowing session the application is running in
+    ///
+    /// Examples: `wayland`, `x11`, `windows`, `macos`
+    pub session_type: Option<String>,
+    /// The name of the desktop environment or compositor, as reported by
+    /// the `XDG_CURRENT_DESKTOP` environment variable
+    pub compositor: Option<String>,
+}
+
+/// The appearance preferences of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Appearance {
+    /// The light or dark preference of the system.
+    pub mode: theme::Mode,
+}
+
+/// Subscribes to changes in the [`Appearance`] preferences of the system.
+///
+/// Changes are reported by the windowing system through the open windows,
+/// so the same [`Appearance`] may be produced once per window. The current
+/// preference can be obtained at any time with [`theme::Mode::system`].
+///
+/// Accent colors are not reported by the windowing system and are,
+/// therefore, not part of the [`Appearance`].
+pub fn appearance() -> Subscription<Appearance> {
+    event::listen_raw(|event, _status, _window| match event {
+        crate::core::Event::Window(window::Event::ThemeChanged(mode)) => {
+            Some(Appearance { mode })
+        }
+        _ => None,
+    })
 }
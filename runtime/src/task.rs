@@ -1,14 +1,20 @@
 //! Create runtime tasks.
+use crate::core::time::Duration;
 use crate::core::widget;
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::future::{self, FutureExt};
 use crate::futures::futures::never::Never;
 use crate::futures::futures::stream::{self, Stream, StreamExt};
+use crate::futures::timer;
 use crate::futures::{boxed_stream, BoxStream, MaybeSend};
 use crate::Action;
 
+use std::collections::BTreeMap;
 use std::future::Future;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::task::Poll;
 
 /// A set of concurrent actions to be performed by the iced runtime.
 ///
@@ -198,6 +204,74 @@ impl<T> Task<T> {
         }
     }
 
+    /// Creates a new [`Task`] that can be aborted with the returned [`Handle`]
+    /// and that is aborted automatically once the given `timeout` has passed.
+    pub fn abortable_with_timeout(self, timeout: Duration) -> (Self, Handle)
+    where
+        T: 'static,
+    {
+        let (task, handle) = self.abortable();
+
+        match task.0 {
+            Some(stream) => {
+                let timeout = {
+                    let handle = handle.clone();
+
+                    timer::sleep(timeout).map(move |_| handle.abort())
+                };
+
+                (Self(Some(boxed_stream(stream.take_until(timeout)))), handle)
+            }
+            None => (Self(None), handle),
+        }
+    }
+
+    /// Registers the [`Task`] with the given [`Id`], so it can be aborted
+    /// later with [`abort`].
+    ///
+    /// If a [`Task`] with the same [`Id`] was registered before, it is
+    /// aborted. This is useful to cancel a previous request when a new one
+    /// supersedes it (e.g. a search query while the user is typing).
+    ///
+    /// The [`Task`] is unregistered once it finishes.
+    pub fn with_id(self, id: Id) -> Self
+    where
+        T: 'static,
+    {
+        let (task, handle) = self.abortable();
+
+        let Some(stream) = task.0 else {
+            let _ = abort(id);
+
+            return Self(None);
+        };
+
+        let registration =
+            REGISTRATIONS.fetch_add(1, atomic::Ordering::Relaxed);
+
+        if let Some((_, previous)) =
+            registry().insert(id, (registration, handle))
+        {
+            previous.abort();
+        }
+
+        // The entry may have been replaced by a newer registration by now
+        let unregister = stream::poll_fn(move |_| {
+            let mut registry = registry();
+
+            if registry
+                .get(&id)
+                .is_some_and(|(current, _)| *current == registration)
+            {
+                let _ = registry.remove(&id);
+            }
+
+            Poll::Ready(None)
+        });
+
+        Self(Some(boxed_stream(stream.chain(unregister))))
+    }
+
     /// Creates a new [`Task`] that runs the given [`Future`] and produces
     /// its output.
     pub fn future(future: impl Future<Output = T> + MaybeSend + 'static) -> Self
@@ -262,6 +336,61 @@ impl Drop for Handle {
     }
 }
 
+/// The identifier of a [`Task`] registered with [`Task::with_id`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+
+impl Id {
+    /// Creates a new unique [`Id`].
+    pub fn unique() -> Self {
+        Self(COUNT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+static REGISTRATIONS: AtomicU64 = AtomicU64::new(0);
+
+static REGISTRY: Mutex<BTreeMap<Id, (u64, Handle)>> =
+    Mutex::new(BTreeMap::new());
+
+fn registry() -> MutexGuard<'static, BTreeMap<Id, (u64, Handle)>> {
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Aborts the [`Task`] registered with the given [`Id`], if any.
+///
+/// Returns `true` if a [`Task`] registered with the [`Id`] was still running.
+pub fn abort(id: Id) -> bool {
+    match registry().remove(&id) {
+        Some((_, handle)) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Delays the given [`Task`] and registers it with the given [`Id`], so that
+/// it only runs if no other [`Task`] is registered with the same [`Id`]
+/// before the `delay` ends.
+///
+/// This is useful to avoid running expensive tasks on every change of some
+/// input (e.g. a search query while the user is typing).
+pub fn debounce<T>(id: Id, delay: Duration, task: Task<T>) -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    let Some(stream) = task.0 else {
+        return Task::none().with_id(id);
+    };
+
+    let delay =
+        stream::once(timer::sleep(delay)).filter_map(|_| future::ready(None));
+
+    Task(Some(boxed_stream(delay.chain(stream)))).with_id(id)
+}
+
 impl<T> Task<Option<T>> {
     /// Executes a new [`Task`] after this one, only when it produces `Some` value.
     ///
@@ -303,6 +432,11 @@ impl<T> From<()> for Task<T> {
 
 /// Creates a new [`Task`] that runs the given [`widget::Operation`] and produces
 /// its output.
+///
+/// The operation runs on the user interfaces of all windows. Use
+/// [`window::operate`] to limit it to a single window.
+///
+/// [`window::operate`]: crate::window::operate
 pub fn widget<T>(operation: impl widget::Operation<T> + 'static) -> Task<T>
 where
     T: Send + 'static,
@@ -361,7 +495,97 @@ pub fn effect<T>(action: impl Into<Action<Never>>) -> Task<T> {
     }))))
 }
 
+/// Creates a new [`Task`] that executes the given [`Action`]s in order and
+/// produces no output.
+pub fn effects<T>(actions: impl IntoIterator<Item = Action<Never>>) -> Task<T> {
+    let actions: Vec<_> = actions.into_iter().collect();
+
+    Task(Some(boxed_stream(
+        stream::iter(actions)
+            .map(|action| action.output().expect_err("no output")),
+    )))
+}
+
 /// Returns the underlying [`Stream`] of the [`Task`].
 pub fn into_stream<T>(task: Task<T>) -> Option<BoxStream<Action<T>>> {
     task.0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::futures::futures::executor::block_on;
+
+    fn outputs<T>(task: Task<T>) -> Vec<T> {
+        let Some(stream) = into_stream(task) else {
+            return Vec::new();
+        };
+
+        block_on(stream.collect::<Vec<_>>())
+            .into_iter()
+            .filter_map(|action| match action {
+                Action::Output(output) => Some(output),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finished_task_is_unregistered() {
+        let id = Id::unique();
+
+        assert_eq!(outputs(Task::done(1).with_id(id)), vec![1]);
+        assert!(!abort(id));
+    }
+
+    #[test]
+    fn running_task_is_aborted() {
+        let id = Id::unique();
+        let task = Task::future(future::pending::<()>()).with_id(id);
+
+        assert!(abort(id));
+        assert!(outputs(task).is_empty());
+        assert!(!abort(id));
+    }
+
+    #[test]
+    fn replaced_task_keeps_newer_registration() {
+        let id = Id::unique();
+
+        let first = Task::done(1).with_id(id);
+        let second = Task::future(future::pending::<i32>()).with_id(id);
+
+        assert!(outputs(first).is_empty());
+        assert!(abort(id));
+        assert!(outputs(second).is_empty());
+    }
+
+    #[test]
+    fn debounce_runs_last_task() {
+        let id = Id::unique();
+        let delay = Duration::from_millis(10);
+
+        let first = debounce(id, delay, Task::done(1));
+        let second = debounce(id, delay, Task::done(2));
+
+        assert!(outputs(first).is_empty());
+        assert_eq!(outputs(second), vec![2]);
+        assert!(!abort(id));
+    }
+
+    #[test]
+    fn abortable_with_timeout() {
+        let (task, handle) = Task::future(future::pending::<()>())
+            .abortable_with_timeout(Duration::from_millis(10));
+
+        assert!(outputs(task).is_empty());
+        assert!(handle.is_aborted());
+
+        let (task, handle) =
+            Task::done(1).abortable_with_timeout(Duration::from_secs(60));
+
+        assert_eq!(outputs(task), vec![1]);
+        assert!(!handle.is_aborted());
+    }
+}
@@ -3,9 +3,11 @@ use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget;
+use crate::core::widget::{self, Operation};
 use crate::core::window;
-use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
+use crate::core::{
+    Clipboard, Element, Layout, Point, Rectangle, Shell, Size, Vector,
+};
 use crate::overlay;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -598,6 +600,78 @@ where
         }
     }
 
+    /// Returns the [`widget::Id`] of the innermost identified widget under the
+    /// given [`Point`] of the [`UserInterface`], if any.
+    ///
+    /// Only widgets that expose their bounds while operating (e.g. a
+    /// `container` or a `scrollable`) can be found.
+    pub fn hit_test(
+        &mut self,
+        renderer: &Renderer,
+        point: Point,
+    ) -> Option<widget::Id> {
+        let mut operation = widget::operation::hit_test(point);
+
+        self.operate(
+            renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(id) => id,
+            _ => None,
+        }
+    }
+
+    /// Returns the bounds of the widget with the given [`widget::Id`] in the
+    /// [`UserInterface`], if found.
+    ///
+    /// Only widgets that expose their bounds while operating (e.g. a
+    /// `container` or a `scrollable`) can be found.
+    pub fn bounds_of(
+        &mut self,
+        renderer: &Renderer,
+        id: widget::Id,
+    ) -> Option<Rectangle> {
+        let mut operation = widget::operation::bounds(id);
+
+        self.operate(
+            renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(bounds) => bounds,
+            _ => None,
+        }
+    }
+
+    /// Computes the size the [`UserInterface`] needs to fit its contents,
+    /// without exceeding the given maximum bounds.
+    ///
+    /// The layout of the [`UserInterface`] is preserved.
+    pub fn measure(&mut self, renderer: &Renderer, max: Size) -> Size {
+        let size = self
+            .root
+            .as_widget()
+            .layout(
+                &mut self.state,
+                renderer,
+                &layout::Limits::new(Size::ZERO, max),
+            )
+            .size();
+
+        // Widgets may cache layout data in their state, so we lay them out
+        // again with the current bounds
+        self.base = self.root.as_widget().layout(
+            &mut self.state,
+            renderer,
+            &layout::Limits::new(Size::ZERO, self.bounds),
+        );
+
+        size
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
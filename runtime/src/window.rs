@@ -3,25 +3,30 @@ pub mod screenshot;
 
 pub use screenshot::Screenshot;
 
-use crate::core::time::Instant;
+use crate::automation;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget;
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    Event, Geometry, Icon, Id, Level, Mode, Monitor, Settings, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Color, Point, Rectangle, Size, Vector};
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
-use crate::futures::Subscription;
+use crate::futures::subscription::{self, Subscription};
+use crate::futures::{boxed_stream, BoxStream};
 use crate::task::{self, Task};
 
 pub use raw_window_handle;
 
 use raw_window_handle::WindowHandle;
+use std::io;
+use std::process::{Child, Command};
 
 /// An operation to be performed on some window.
 #[allow(missing_debug_implementations)]
 pub enum Action {
     /// Opens a new window with some [`Settings`].
-    Open(Id, Settings, oneshot::Sender<Id>),
+    Open(Id, Settings, oneshot::Sender<Result<Id, Error>>),
 
     /// Close the window and exits the application.
     Close(Id),
@@ -42,6 +47,22 @@ pub enum Action {
     /// Resize the window to the given logical dimensions.
     Resize(Id, Size),
 
+    /// Set the minimum logical dimensions of the window.
+    ///
+    /// Providing `None` removes the constraint.
+    SetMinSize(Id, Option<Size>),
+
+    /// Set the maximum logical dimensions of the window.
+    ///
+    /// Providing `None` removes the constraint.
+    SetMaxSize(Id, Option<Size>),
+
+    /// Set whether the window can be resized by the user.
+    SetResizable(Id, bool),
+
+    /// Resize the window to fit its contents.
+    ResizeToContent(Id),
+
     /// Get the current logical dimensions of the window.
     GetSize(Id, oneshot::Sender<Size>),
 
@@ -66,11 +87,68 @@ pub enum Action {
     /// Get the current scale factor (DPI) of the window.
     GetScaleFactor(Id, oneshot::Sender<f32>),
 
+    /// Override the scale factor provided by the windowing system for the
+    /// window.
+    ///
+    /// The scale factor of the program is still applied on top of it.
+    SetScaleFactor(Id, f32),
+
+    /// Restore the scale factor provided by the windowing system for the
+    /// window, discarding any override.
+    ResetScaleFactor(Id),
+
+    /// Increase the zoom factor applied to all the windows of the
+    /// application, and get the new zoom factor.
+    ZoomIn(oneshot::Sender<f32>),
+
+    /// Decrease the zoom factor applied to all the windows of the
+    /// application, and get the new zoom factor.
+    ZoomOut(oneshot::Sender<f32>),
+
+    /// Reset the zoom factor applied to all the windows of the
+    /// application, and get the new zoom factor.
+    ZoomReset(oneshot::Sender<f32>),
+
+    /// Get the [`Monitor`] the window is currently on, if known.
+    GetMonitor(Id, oneshot::Sender<Option<Monitor>>),
+
+    /// Get all the [`Monitor`]s available in the system.
+    GetMonitors(oneshot::Sender<Vec<Monitor>>),
+
+    /// Get the current [`Geometry`] of the window.
+    GetGeometry(Id, oneshot::Sender<Geometry>),
+
+    /// Restore a [`Geometry`] previously captured from a window.
+    SetGeometry(Id, Geometry),
+
+    /// Get the [`widget::Id`] of the innermost identified widget under the
+    /// given logical coordinates of the window, if any.
+    HitTest(Id, Point, oneshot::Sender<Option<widget::Id>>),
+
+    /// Dispatch an [`Event`](crate::core::Event) to the window, as if it was
+    /// produced by the windowing system.
+    DispatchEvent(Id, crate::core::Event),
+
+    /// Start recording the user interactions of the window.
+    StartRecording(Id),
+
+    /// Stop recording the user interactions of the window.
+    StopRecording(Id, oneshot::Sender<Vec<automation::Interaction>>),
+
+    /// Run a widget operation only on the user interface of the window.
+    Operate(Id, Box<dyn widget::Operation>),
+
     /// Move the window to the given logical coordinates.
     ///
     /// Unsupported on Wayland.
     Move(Id, Point),
 
+    /// Move the window relative to the outer top-left corner of another
+    /// window, by the given logical offset.
+    ///
+    /// Unsupported on Wayland.
+    MoveRelative(Id, Id, Vector),
+
     /// Change the [`Mode`] of the window.
     ChangeMode(Id, Mode),
 
@@ -114,6 +192,9 @@ pub enum Action {
     /// - **Web / Wayland:** Unsupported.
     GainFocus(Id),
 
+    /// Show the window, if hidden, and bring it to the front.
+    Show(Id),
+
     /// Change the window [`Level`].
     ChangeLevel(Id, Level),
 
@@ -142,12 +223,47 @@ pub enum Action {
     ///   said, it's usually in the same ballpark as on Windows.
     ChangeIcon(Id, Icon),
 
+    /// Set the title of the window.
+    ///
+    /// The title is kept until the title of the program changes.
+    SetTitle(Id, String),
+
     /// Runs the closure with the native window handle of the window with the given [`Id`].
     RunWithHandle(Id, Box<dyn FnOnce(WindowHandle<'_>) + Send>),
 
+    /// Override the background color of the window, regardless of the
+    /// appearance of the program.
+    ///
+    /// Providing `None` will restore the background color of the appearance.
+    SetBackgroundColor(Id, Option<Color>),
+
+    /// Spawn a [`Command`] with an activation token requested by the window,
+    /// so the spawned application can obtain focus.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland:** The token is passed through the
+    ///   `DESKTOP_STARTUP_ID` and `XDG_ACTIVATION_TOKEN` environment variables.
+    /// - **Other platforms:** The [`Command`] is spawned without a token.
+    SpawnWithActivationToken(Id, Command, oneshot::Sender<io::Result<Child>>),
+
+    /// Request an activation token from the window, which can be handed to
+    /// another application so it can obtain focus.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland:** The token is requested through the startup
+    ///   notification protocol.
+    /// - **Other platforms:** Unsupported; `None` is always produced.
+    RequestActivationToken(Id, oneshot::Sender<Option<String>>),
+
     /// Screenshot the viewport of the window.
     Screenshot(Id, oneshot::Sender<Screenshot>),
 
+    /// Screenshot the viewport of the window into the given buffer, reusing
+    /// its allocation.
+    ScreenshotInto(Id, Vec<u8>, oneshot::Sender<Screenshot>),
+
     /// Enables mouse passthrough for the given window.
     ///
     /// This disables mouse events for the window and passes mouse events
@@ -159,6 +275,27 @@ pub enum Action {
     /// This enables mouse events for the window and stops mouse events
     /// from being passed to whatever is underneath.
     DisableMousePassthrough(Id),
+
+    /// Set the input region of the window, in logical coordinates.
+    ///
+    /// Mouse and touch interactions starting outside of the region are
+    /// ignored. `None` makes the whole window accept input.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **All platforms:** Only an empty region lets the interactions pass
+    ///   through to whatever is underneath the window.
+    SetInputRegion(Id, Option<Vec<Rectangle>>),
+
+    /// Set the opaque region of the window, in logical coordinates.
+    ///
+    /// The region is a hint for the compositor; `None` marks the whole
+    /// window as possibly transparent.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **All platforms:** Unsupported by the `winit` shell, which ignores it.
+    SetOpaqueRegion(Id, Option<Vec<Rectangle>>),
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -167,6 +304,10 @@ pub enum Action {
 /// refresh rate of the first application window. Note that this rate may be variable, as it is
 /// normally managed by the graphics driver and/or the OS.
 ///
+/// All the windows redrawn during the same frame share the same timestamp,
+/// which is taken when the event loop wakes up to process the frame. Windows
+/// that are occluded stop producing frames until they become visible again.
+///
 /// In any case, this [`Subscription`] is useful to smoothly draw application-driven
 /// animations without missing any frames.
 pub fn frames() -> Subscription<Instant> {
@@ -176,6 +317,161 @@ pub fn frames() -> Subscription<Instant> {
     })
 }
 
+/// A frame of a window, produced by [`frames_every`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// The [`Id`] of the window.
+    pub window: Id,
+    /// The timestamp of the frame.
+    pub at: Instant,
+    /// The time elapsed since the previous [`Frame`] of the window, if any.
+    pub delta: Option<Duration>,
+    /// The target interval between frames of the window; that is, the
+    /// inverse of its target refresh rate.
+    pub interval: Duration,
+}
+
+/// Subscribes to the frames of each window of the running application,
+/// producing at most one [`Frame`] every `interval` per window.
+///
+/// Unlike [`frames`], this [`Subscription`] can be used to drive
+/// application-driven animations at a fixed rate lower than the refresh rate
+/// of the display, avoiding needless updates.
+///
+/// A window redrawn before its `interval` ends does not lose its frame; the
+/// [`Frame`] is produced once the `interval` ends, even if no other redraws
+/// follow.
+pub fn frames_every(interval: Duration) -> Subscription<Frame> {
+    struct Frames(Duration);
+
+    impl subscription::Recipe for Frames {
+        type Output = Frame;
+
+        fn hash(&self, state: &mut subscription::Hasher) {
+            use std::hash::Hash;
+
+            std::any::TypeId::of::<Self>().hash(state);
+            self.0.hash(state);
+        }
+
+        fn stream(
+            self: Box<Self>,
+            input: subscription::EventStream,
+        ) -> BoxStream<Self::Output> {
+            use crate::futures::futures::stream::{self, FuturesUnordered};
+            use crate::futures::futures::{Future, FutureExt, StreamExt};
+            use crate::futures::timer;
+            use std::collections::{HashMap, HashSet};
+            use std::task::Poll;
+
+            fn schedule(
+                window: Id,
+                duration: Duration,
+            ) -> impl Future<Output = Id> {
+                timer::sleep(duration).map(move |_| window)
+            }
+
+            let interval = self.0;
+            let mut input = input;
+            let mut last_frames: HashMap<Id, Instant> = HashMap::new();
+            let mut scheduled = HashSet::new();
+            let mut timers = FuturesUnordered::new();
+
+            // A dropped frame does not produce any messages and, therefore,
+            // may never cause another redraw. Instead, we schedule a frame
+            // for when the interval of the window ends.
+            boxed_stream(stream::poll_fn(move |cx| {
+                while let Poll::Ready(event) = input.poll_next_unpin(cx) {
+                    let Some(event) = event else {
+                        return Poll::Ready(None);
+                    };
+
+                    let subscription::Event::Interaction {
+                        window,
+                        event: crate::core::Event::Window(event),
+                        ..
+                    } = event
+                    else {
+                        continue;
+                    };
+
+                    match event {
+                        Event::RedrawRequested(at) => {
+                            let delta = match last_frames.get(&window) {
+                                Some(last)
+                                    if at.duration_since(*last) < interval =>
+                                {
+                                    if scheduled.insert(window) {
+                                        timers.push(schedule(
+                                            window,
+                                            interval - at.duration_since(*last),
+                                        ));
+                                    }
+
+                                    continue;
+                                }
+                                Some(last) => Some(at.duration_since(*last)),
+                                None => None,
+                            };
+
+                            let _ = last_frames.insert(window, at);
+
+                            return Poll::Ready(Some(Frame {
+                                window,
+                                at,
+                                delta,
+                                interval,
+                            }));
+                        }
+                        Event::Closed => {
+                            let _ = last_frames.remove(&window);
+                            let _ = scheduled.remove(&window);
+                        }
+                        _ => {}
+                    }
+                }
+
+                while let Poll::Ready(Some(window)) = timers.poll_next_unpin(cx)
+                {
+                    if !scheduled.remove(&window) {
+                        continue;
+                    }
+
+                    let at = Instant::now();
+
+                    let delta = match last_frames.get(&window) {
+                        Some(last) if at.duration_since(*last) < interval => {
+                            let _ = scheduled.insert(window);
+
+                            timers.push(schedule(
+                                window,
+                                interval - at.duration_since(*last),
+                            ));
+
+                            continue;
+                        }
+                        Some(last) => Some(at.duration_since(*last)),
+                        None => None,
+                    };
+
+                    let _ = last_frames.insert(window, at);
+
+                    return Poll::Ready(Some(Frame {
+                        window,
+                        at,
+                        delta,
+                        interval,
+                    }));
+                }
+
+                Poll::Pending
+            }))
+        }
+    }
+
+    subscription::from_recipe(Frames(interval))
+}
+
 /// Subscribes to all window events of the running application.
 pub fn events() -> Subscription<(Id, Event)> {
     event::listen_with(|event, _status, id| {
@@ -234,6 +530,15 @@ pub fn close_requests() -> Subscription<Id> {
 /// Opens a new window with the given [`Settings`]; producing the [`Id`]
 /// of the new window on completion.
 pub fn open(settings: Settings) -> (Id, Task<Id>) {
+    let (id, task) = try_open(settings);
+
+    (id, task.and_then(Task::done))
+}
+
+/// Opens a new window with the given [`Settings`], just like [`open`];
+/// but the returned [`Task`] produces an [`Error`] if the window could
+/// not be created.
+pub fn try_open(settings: Settings) -> (Id, Task<Result<Id, Error>>) {
     let id = Id::unique();
 
     (
@@ -269,6 +574,33 @@ pub fn resize<T>(id: Id, new_size: Size) -> Task<T> {
     task::effect(crate::Action::Window(Action::Resize(id, new_size)))
 }
 
+/// Resizes the window to fit its contents.
+///
+/// The contents are measured without exceeding the size of the monitor the
+/// window is on. Widgets that fill the available space will take all of it.
+pub fn resize_to_content<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ResizeToContent(id)))
+}
+
+/// Sets the minimum logical dimensions of the window.
+///
+/// Providing `None` removes the constraint.
+pub fn set_min_size<T>(id: Id, size: Option<Size>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetMinSize(id, size)))
+}
+
+/// Sets the maximum logical dimensions of the window.
+///
+/// Providing `None` removes the constraint.
+pub fn set_max_size<T>(id: Id, size: Option<Size>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetMaxSize(id, size)))
+}
+
+/// Sets whether the window can be resized by the user.
+pub fn set_resizable<T>(id: Id, resizable: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetResizable(id, resizable)))
+}
+
 /// Get the window's size in logical dimensions.
 pub fn get_size(id: Id) -> Task<Size> {
     task::oneshot(move |channel| {
@@ -314,11 +646,131 @@ pub fn get_scale_factor(id: Id) -> Task<f32> {
     })
 }
 
+/// Gets the [`Monitor`] the window with the given [`Id`] is currently on, if
+/// known.
+pub fn get_monitor(id: Id) -> Task<Option<Monitor>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetMonitor(id, channel))
+    })
+}
+
+/// Gets the current [`Geometry`] of the window with the given [`Id`].
+///
+/// The [`Geometry`] can be persisted and restored later with
+/// [`set_geometry`].
+pub fn get_geometry(id: Id) -> Task<Geometry> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetGeometry(id, channel))
+    })
+}
+
+/// Restores a [`Geometry`] captured with [`get_geometry`] in the window with
+/// the given [`Id`].
+///
+/// If the monitor of the [`Geometry`] is not available anymore, the window
+/// stays on its current monitor.
+pub fn set_geometry<T>(id: Id, geometry: Geometry) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetGeometry(id, geometry)))
+}
+
+/// Gets all the [`Monitor`]s available in the system.
+///
+/// This can be used to let users choose the monitor a window should be
+/// opened on, for instance.
+pub fn monitors() -> Task<Vec<Monitor>> {
+    task::oneshot(|channel| crate::Action::Window(Action::GetMonitors(channel)))
+}
+
+/// Gets the [`widget::Id`] of the innermost identified widget under the given
+/// logical coordinates of the window with the given [`Id`], if any.
+pub fn hit_test(id: Id, point: Point) -> Task<Option<widget::Id>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::HitTest(id, point, channel))
+    })
+}
+
+/// Runs the given [`widget::Operation`] only on the user interface of the
+/// window with the given [`Id`] and produces its output.
+///
+/// Unlike [`task::widget`], the widget trees of other windows are not
+/// traversed. The operation can be further limited to the subtree of some
+/// container with [`widget::operation::scope`].
+pub fn operate<T>(
+    id: Id,
+    operation: impl widget::Operation<T> + 'static,
+) -> Task<T>
+where
+    T: Send + 'static,
+{
+    task::channel(move |sender| {
+        let operation =
+            widget::operation::map(Box::new(operation), move |value| {
+                let _ = sender.clone().try_send(value);
+            });
+
+        crate::Action::Window(Action::Operate(id, Box::new(operation)))
+    })
+}
+
+/// Overrides the scale factor provided by the windowing system for the window
+/// with the given [`Id`].
+///
+/// The scale factor of the program is still applied on top of it, and the
+/// rest of the windows are not affected.
+///
+/// Scale factors that are not finite or not positive are ignored.
+pub fn set_scale_factor<T>(id: Id, scale_factor: f32) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetScaleFactor(
+        id,
+        scale_factor,
+    )))
+}
+
+/// Restores the scale factor provided by the windowing system for the window
+/// with the given [`Id`], discarding any override set with
+/// [`set_scale_factor`].
+pub fn reset_scale_factor<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ResetScaleFactor(id)))
+}
+
+/// Increases the zoom factor applied to all the windows of the application.
+///
+/// The zoom factor persists across windows and is applied on top of any
+/// other scale factor. The resulting [`Task`] produces the new zoom factor.
+pub fn zoom_in() -> Task<f32> {
+    task::oneshot(|channel| crate::Action::Window(Action::ZoomIn(channel)))
+}
+
+/// Decreases the zoom factor applied to all the windows of the application.
+///
+/// The resulting [`Task`] produces the new zoom factor.
+pub fn zoom_out() -> Task<f32> {
+    task::oneshot(|channel| crate::Action::Window(Action::ZoomOut(channel)))
+}
+
+/// Resets the zoom factor applied to all the windows of the application.
+///
+/// The resulting [`Task`] produces the new zoom factor.
+pub fn zoom_reset() -> Task<f32> {
+    task::oneshot(|channel| crate::Action::Window(Action::ZoomReset(channel)))
+}
+
 /// Moves the window to the given logical coordinates.
 pub fn move_to<T>(id: Id, position: Point) -> Task<T> {
     task::effect(crate::Action::Window(Action::Move(id, position)))
 }
 
+/// Moves the window with the given [`Id`] relative to the outer top-left
+/// corner of the `anchor` window, by the given logical `offset`.
+///
+/// Combined with [`get_size`], this can be used to place a window right next
+/// to another one.
+pub fn move_relative<T>(id: Id, anchor: Id, offset: Vector) -> Task<T> {
+    task::effect(crate::Action::Window(Action::MoveRelative(
+        id, anchor, offset,
+    )))
+}
+
 /// Changes the [`Mode`] of the window.
 pub fn change_mode<T>(id: Id, mode: Mode) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeMode(id, mode)))
@@ -367,6 +819,14 @@ pub fn gain_focus<T>(id: Id) -> Task<T> {
     task::effect(crate::Action::Window(Action::GainFocus(id)))
 }
 
+/// Shows the window, if hidden, and brings it to the front.
+///
+/// This is useful to restore a window hidden by a close request when
+/// [`Settings::hide_on_close_request`] is enabled.
+pub fn show<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::Show(id)))
+}
+
 /// Changes the window [`Level`].
 pub fn change_level<T>(id: Id, level: Level) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeLevel(id, level)))
@@ -392,6 +852,13 @@ pub fn change_icon<T>(id: Id, icon: Icon) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeIcon(id, icon)))
 }
 
+/// Sets the title of the window, regardless of the title of the program.
+///
+/// The title is kept until the title returned by the program changes.
+pub fn set_title<T>(id: Id, title: impl Into<String>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetTitle(id, title.into())))
+}
+
 /// Runs the given callback with the native window handle for the window with the given id.
 ///
 /// Note that if the window closes before this call is processed the callback will not be run.
@@ -412,6 +879,59 @@ where
     })
 }
 
+/// Overrides the background [`Color`] of the window with the given [`Id`],
+/// regardless of the appearance of the program.
+///
+/// This is useful to make a single window fully transparent, for instance.
+/// Providing `None` will restore the background [`Color`] of the appearance.
+pub fn set_background_color<T>(id: Id, color: Option<Color>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetBackgroundColor(id, color)))
+}
+
+/// Spawns the given [`Command`] with an activation token requested by the
+/// window with the given [`Id`].
+///
+/// The spawned application will receive the token through the
+/// `XDG_ACTIVATION_TOKEN` and `DESKTOP_STARTUP_ID` environment variables, which
+/// allows it to obtain focus without being considered focus stealing.
+///
+/// If activation tokens are not supported by the platform, the [`Command`] is
+/// spawned without one.
+pub fn spawn_with_activation_token(
+    id: Id,
+    command: Command,
+) -> Task<io::Result<Child>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::SpawnWithActivationToken(
+            id, command, channel,
+        ))
+    })
+}
+
+/// Requests an activation token from the window with the given [`Id`].
+///
+/// The token can be handed to another application, so it can obtain focus
+/// without being considered focus stealing. Use [`set_activation_token`] to
+/// pass it to a [`Command`] before spawning it.
+///
+/// Produces `None` if activation tokens are not supported by the platform.
+pub fn request_activation_token(id: Id) -> Task<Option<String>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::RequestActivationToken(id, channel))
+    })
+}
+
+/// Sets the given activation token in the environment of a [`Command`].
+///
+/// The token is set in the `XDG_ACTIVATION_TOKEN` and `DESKTOP_STARTUP_ID`
+/// environment variables, which are used by Wayland and X11 applications,
+/// respectively.
+pub fn set_activation_token(command: &mut Command, token: &str) {
+    let _ = command
+        .env("XDG_ACTIVATION_TOKEN", token)
+        .env("DESKTOP_STARTUP_ID", token);
+}
+
 /// Captures a [`Screenshot`] from the window.
 pub fn screenshot(id: Id) -> Task<Screenshot> {
     task::oneshot(move |channel| {
@@ -419,6 +939,17 @@ pub fn screenshot(id: Id) -> Task<Screenshot> {
     })
 }
 
+/// Captures a [`Screenshot`] from the window into the given buffer.
+///
+/// The allocation of the buffer is reused for the bytes of the
+/// [`Screenshot`], and it can be reclaimed with [`Screenshot::into_buffer`]
+/// to capture the next one without allocating.
+pub fn screenshot_into(id: Id, buffer: Vec<u8>) -> Task<Screenshot> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::ScreenshotInto(id, buffer, channel))
+    })
+}
+
 /// Enables mouse passthrough for the given window.
 ///
 /// This disables mouse events for the window and passes mouse events
@@ -434,3 +965,94 @@ pub fn enable_mouse_passthrough<Message>(id: Id) -> Task<Message> {
 pub fn disable_mouse_passthrough<Message>(id: Id) -> Task<Message> {
     task::effect(crate::Action::Window(Action::DisableMousePassthrough(id)))
 }
+
+/// Sets the input region of the window with the given [`Id`], in logical
+/// coordinates.
+///
+/// Mouse and touch interactions starting outside of the region are ignored.
+/// An empty region makes the window click-through, while `None` makes the
+/// whole window accept input again.
+pub fn set_input_region<T>(id: Id, region: Option<Vec<Rectangle>>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetInputRegion(id, region)))
+}
+
+/// Sets the opaque region of the window with the given [`Id`], in logical
+/// coordinates.
+///
+/// The opaque region is a hint that lets the compositor skip drawing
+/// whatever is behind those parts of the window. `None` marks the whole
+/// window as possibly transparent.
+///
+/// ## Platform-specific
+///
+/// - **All platforms:** The `winit` shell cannot set the opaque region of
+///   a window yet, so the hint is ignored.
+pub fn set_opaque_region<T>(id: Id, region: Option<Vec<Rectangle>>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetOpaqueRegion(id, region)))
+}
+
+/// An error that occurred while opening a window.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The windowing system failed to create the window.
+    #[error("the window could not be created: {0}")]
+    CreationFailed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::event;
+    use crate::futures::futures::channel::mpsc;
+    use crate::futures::futures::executor::block_on;
+    use crate::futures::futures::StreamExt;
+
+    fn redraw(window: Id, at: Instant) -> subscription::Event {
+        subscription::Event::Interaction {
+            window,
+            event: crate::core::Event::Window(Event::RedrawRequested(at)),
+            status: event::Status::Ignored,
+        }
+    }
+
+    #[test]
+    fn frames_keep_coming_after_a_dropped_frame() {
+        let interval = Duration::from_millis(20);
+        let window = Id::unique();
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        let mut recipes = subscription::into_recipes(frames_every(interval));
+        let recipe = recipes.pop().expect("frames_every has a recipe");
+        let mut frames = recipe.stream(boxed_stream(receiver));
+
+        let start = Instant::now();
+
+        sender.unbounded_send(redraw(window, start)).unwrap();
+
+        let first = block_on(frames.next()).unwrap();
+        assert_eq!(first.at, start);
+        assert_eq!(first.delta, None);
+        assert_eq!(first.interval, interval);
+
+        let mut last = first;
+
+        for _ in 0..3 {
+            // The application redraws right away, before the interval ends,
+            // and then waits for the next frame
+            sender
+                .unbounded_send(redraw(window, Instant::now()))
+                .unwrap();
+
+            let frame = block_on(frames.next()).unwrap();
+
+            assert_eq!(frame.window, window);
+            assert!(frame.at.duration_since(last.at) >= interval);
+
+            last = frame;
+        }
+
+        assert!(start.elapsed() >= interval * 3);
+    }
+}
@@ -44,6 +44,14 @@ impl Screenshot {
         }
     }
 
+    /// Turns the [`Screenshot`] into a buffer of its bytes.
+    ///
+    /// The allocation of the bytes is reused if the [`Screenshot`] is their
+    /// only owner; otherwise, they are copied.
+    pub fn into_buffer(self) -> Vec<u8> {
+        Vec::from(self.bytes)
+    }
+
     /// Crops a [`Screenshot`] to the provided `region`. This will always be relative to the
     /// top-left corner of the [`Screenshot`].
     pub fn crop(&self, region: Rectangle<u32>) -> Result<Self, CropError> {
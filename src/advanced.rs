@@ -7,6 +7,12 @@ pub mod subscription {
     };
 }
 
+pub mod test {
+    //! Drive programs headlessly, without a window.
+    pub use crate::runtime::program::test::{Error, Simulator};
+    pub use crate::runtime::Program;
+}
+
 pub mod widget {
     //! Create custom widgets and operate on them.
     pub use crate::core::widget::*;
@@ -31,6 +31,7 @@
 //! }
 //! ```
 use crate::program::{self, Program};
+use crate::settings;
 use crate::window;
 use crate::{
     Element, Executor, Font, Result, Settings, Size, Subscription, Task,
@@ -193,6 +194,44 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`Settings::scrolling`] behavior of the [`Application`].
+    pub fn scrolling(self, scrolling: settings::Scrolling) -> Self {
+        Self {
+            settings: Settings {
+                scrolling,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Application`] should suppress repeated key presses.
+    ///
+    /// See [`Settings::suppress_key_repeats`] for details.
+    pub fn suppress_key_repeats(self, suppress_key_repeats: bool) -> Self {
+        Self {
+            settings: Settings {
+                suppress_key_repeats,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Application`] should skip animations in favor of instant
+    /// transitions.
+    ///
+    /// See [`Settings::reduced_motion`] for details.
+    pub fn reduced_motion(self, reduced_motion: bool) -> Self {
+        Self {
+            settings: Settings {
+                reduced_motion,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Application`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -239,6 +278,17 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::hide_on_close_request`] of the [`Application`].
+    pub fn hide_on_close_request(self, hide_on_close_request: bool) -> Self {
+        Self {
+            window: window::Settings {
+                hide_on_close_request,
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::size`] of the [`Application`].
     pub fn window_size(self, size: impl Into<Size>) -> Self {
         Self {
@@ -379,6 +429,24 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the logic used to merge adjacent messages of the [`Application`] before
+    /// they are processed by its update logic.
+    ///
+    /// If the closure returns `Some`, the two messages are replaced by the
+    /// returned one.
+    pub fn coalesce(
+        self,
+        f: impl Fn(&P::Message, &P::Message) -> Option<P::Message>,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Application {
+            raw: program::with_coalesce(self.raw, f),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
     /// Sets the executor of the [`Application`].
     pub fn executor<E>(
         self,
@@ -1,6 +1,7 @@
 //! Create and run daemons that run in the background.
 use crate::application;
 use crate::program::{self, Program};
+use crate::settings;
 use crate::window;
 use crate::{Element, Executor, Font, Result, Settings, Subscription, Task};
 
@@ -140,6 +141,44 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the [`Settings::scrolling`] behavior of the [`Daemon`].
+    pub fn scrolling(self, scrolling: settings::Scrolling) -> Self {
+        Self {
+            settings: Settings {
+                scrolling,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Daemon`] should suppress repeated key presses.
+    ///
+    /// See [`Settings::suppress_key_repeats`] for details.
+    pub fn suppress_key_repeats(self, suppress_key_repeats: bool) -> Self {
+        Self {
+            settings: Settings {
+                suppress_key_repeats,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Daemon`] should skip animations in favor of instant
+    /// transitions.
+    ///
+    /// See [`Settings::reduced_motion`] for details.
+    pub fn reduced_motion(self, reduced_motion: bool) -> Self {
+        Self {
+            settings: Settings {
+                reduced_motion,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Daemon`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -211,6 +250,23 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the style logic of the [`Daemon`] for each window, allowing
+    /// each window to have its own [`Appearance`].
+    ///
+    /// It takes precedence over [`style`](Self::style), regardless of the
+    /// order in which both are set.
+    pub fn style_for(
+        self,
+        f: impl Fn(&P::State, window::Id, &P::Theme) -> Appearance,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Daemon {
+            raw: program::with_style_for(self.raw, f),
+            settings: self.settings,
+        }
+    }
+
     /// Sets the scale factor of the [`Daemon`].
     pub fn scale_factor(
         self,
@@ -224,6 +280,23 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the logic used to merge adjacent messages of the [`Daemon`] before
+    /// they are processed by its update logic.
+    ///
+    /// If the closure returns `Some`, the two messages are replaced by the
+    /// returned one.
+    pub fn coalesce(
+        self,
+        f: impl Fn(&P::Message, &P::Message) -> Option<P::Message>,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Daemon {
+            raw: program::with_coalesce(self.raw, f),
+            settings: self.settings,
+        }
+    }
+
     /// Sets the executor of the [`Daemon`].
     pub fn executor<E>(
         self,
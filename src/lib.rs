@@ -499,15 +499,16 @@ pub mod window;
 pub mod advanced;
 
 pub use crate::core::alignment;
+pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Background, Border, Color, ContentFit, Degrees, Gradient,
-    Length, Padding, Pixels, Point, Radians, Rectangle, Rotation, Shadow, Size,
-    Theme, Transformation, Vector,
+    Alignment, Animation, Background, Border, Color, ContentFit, Degrees,
+    Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Rotation,
+    Shadow, Size, Theme, Transformation, Vector,
 };
 pub use crate::runtime::exit;
 pub use iced_futures::Subscription;
@@ -519,7 +520,7 @@ pub use Length::{Fill, FillPortion, Shrink};
 
 pub mod task {
     //! Create runtime tasks.
-    pub use crate::runtime::task::{Handle, Task};
+    pub use crate::runtime::task::{abort, debounce, Handle, Id, Task};
 }
 
 pub mod clipboard {
@@ -574,10 +575,23 @@ pub mod mouse {
     };
 }
 
+pub mod automation {
+    //! Record user interactions and replay them.
+    pub use crate::runtime::automation::{
+        replay, start_recording, stop_recording, Interaction,
+    };
+}
+
+#[cfg(feature = "event-log")]
+pub mod event_log {
+    //! Record events to a compact binary log and replay them.
+    pub use crate::runtime::event_log::{read, replay, Entry, Error, Recorder};
+}
+
 #[cfg(feature = "system")]
 pub mod system {
     //! Retrieve system information.
-    pub use crate::runtime::system::Information;
+    pub use crate::runtime::system::{appearance, Appearance, Information};
     pub use crate::shell::system::*;
 }
 
@@ -58,10 +58,33 @@ pub trait Program: Sized {
         DefaultStyle::default_style(theme)
     }
 
+    /// Returns the [`Appearance`] of a specific window, if the program
+    /// styles its windows individually.
+    ///
+    /// When `None`, the [`Appearance`] given by [`style`] is used.
+    ///
+    /// [`style`]: Self::style
+    fn style_for(
+        &self,
+        _state: &Self::State,
+        _window: window::Id,
+        _theme: &Self::Theme,
+    ) -> Option<Appearance> {
+        None
+    }
+
     fn scale_factor(&self, _state: &Self::State, _window: window::Id) -> f64 {
         1.0
     }
 
+    fn coalesce(
+        &self,
+        _previous: &Self::Message,
+        _next: &Self::Message,
+    ) -> Option<Self::Message> {
+        None
+    }
+
     /// Runs the [`Program`].
     ///
     /// The state of the [`Program`] must implement [`Default`].
@@ -157,9 +180,27 @@ pub trait Program: Sized {
                 self.program.style(&self.state, theme)
             }
 
+            fn style_for(
+                &self,
+                window: window::Id,
+                theme: &Self::Theme,
+            ) -> Appearance {
+                self.program
+                    .style_for(&self.state, window, theme)
+                    .unwrap_or_else(|| self.program.style(&self.state, theme))
+            }
+
             fn scale_factor(&self, window: window::Id) -> f64 {
                 self.program.scale_factor(&self.state, window)
             }
+
+            fn coalesce(
+                &self,
+                previous: &Self::Message,
+                next: &Self::Message,
+            ) -> Option<Self::Message> {
+                self.program.coalesce(previous, next)
+            }
         }
 
         #[allow(clippy::needless_update)]
@@ -184,6 +225,9 @@ pub trait Program: Sized {
                 default_font: settings.default_font,
                 default_text_size: settings.default_text_size,
                 antialiasing: settings.antialiasing,
+                scrolling: settings.scrolling,
+                suppress_key_repeats: settings.suppress_key_repeats,
+                reduced_motion: settings.reduced_motion,
             }
             .into(),
             renderer_settings,
@@ -256,9 +300,26 @@ pub fn with_title<P: Program>(
             self.program.style(state, theme)
         }
 
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
     }
 
     WithTitle { program, title }
@@ -326,9 +387,26 @@ pub fn with_subscription<P: Program>(
             self.program.style(state, theme)
         }
 
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
     }
 
     WithSubscription {
@@ -399,9 +477,26 @@ pub fn with_theme<P: Program>(
             self.program.style(state, theme)
         }
 
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
     }
 
     WithTheme { program, theme: f }
@@ -434,6 +529,15 @@ pub fn with_style<P: Program>(
             (self.style)(state, theme)
         }
 
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
         fn title(&self, state: &Self::State, window: window::Id) -> String {
             self.program.title(state, window)
         }
@@ -472,11 +576,109 @@ pub fn with_style<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
     }
 
     WithStyle { program, style: f }
 }
 
+pub fn with_style_for<P: Program>(
+    program: P,
+    f: impl Fn(&P::State, window::Id, &P::Theme) -> Appearance,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithStyleFor<P, F> {
+        program: P,
+        style_for: F,
+    }
+
+    impl<P: Program, F> Program for WithStyleFor<P, F>
+    where
+        F: Fn(&P::State, window::Id, &P::Theme) -> Appearance,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            Some((self.style_for)(state, window, theme))
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
+    }
+
+    WithStyleFor {
+        program,
+        style_for: f,
+    }
+}
+
 pub fn with_scale_factor<P: Program>(
     program: P,
     f: impl Fn(&P::State, window::Id) -> f64,
@@ -539,9 +741,26 @@ pub fn with_scale_factor<P: Program>(
             self.program.style(state, theme)
         }
 
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             (self.scale_factor)(state, window)
         }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
     }
 
     WithScaleFactor {
@@ -550,6 +769,96 @@ pub fn with_scale_factor<P: Program>(
     }
 }
 
+pub fn with_coalesce<P: Program>(
+    program: P,
+    f: impl Fn(&P::Message, &P::Message) -> Option<P::Message>,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithCoalesce<P, F> {
+        program: P,
+        coalesce: F,
+    }
+
+    impl<P: Program, F> Program for WithCoalesce<P, F>
+    where
+        F: Fn(&P::Message, &P::Message) -> Option<P::Message>,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            (self.coalesce)(previous, next)
+        }
+    }
+
+    WithCoalesce {
+        program,
+        coalesce: f,
+    }
+}
+
 pub fn with_executor<P: Program, E: Executor>(
     program: P,
 ) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
@@ -613,9 +922,26 @@ pub fn with_executor<P: Program, E: Executor>(
             self.program.style(state, theme)
         }
 
+        fn style_for(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            theme: &Self::Theme,
+        ) -> Option<Appearance> {
+            self.program.style_for(state, window, theme)
+        }
+
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn coalesce(
+            &self,
+            previous: &Self::Message,
+            next: &Self::Message,
+        ) -> Option<Self::Message> {
+            self.program.coalesce(previous, next)
+        }
     }
 
     WithExecutor {
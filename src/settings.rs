@@ -3,6 +3,8 @@ use crate::{Font, Pixels};
 
 use std::borrow::Cow;
 
+pub use iced_winit::settings::Scrolling;
+
 /// The settings of an iced program.
 #[derive(Debug, Clone)]
 pub struct Settings {
@@ -35,6 +37,22 @@ pub struct Settings {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
+
+    /// The [`Scrolling`] behavior of the application.
+    pub scrolling: Scrolling,
+
+    /// Whether to suppress the key presses synthesized by the system while a
+    /// key is held down.
+    ///
+    /// By default, it is disabled.
+    pub suppress_key_repeats: bool,
+
+    /// Whether animations should be skipped in favor of instant transitions.
+    ///
+    /// This can be used to honor the accessibility preferences of the user.
+    ///
+    /// By default, it is disabled.
+    pub reduced_motion: bool,
 }
 
 impl Default for Settings {
@@ -45,6 +63,9 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            scrolling: Scrolling::default(),
+            suppress_key_repeats: false,
+            reduced_motion: false,
         }
     }
 }
@@ -54,6 +75,9 @@ impl From<Settings> for iced_winit::Settings {
         iced_winit::Settings {
             id: settings.id,
             fonts: settings.fonts,
+            scrolling: settings.scrolling,
+            suppress_key_repeats: settings.suppress_key_repeats,
+            reduced_motion: settings.reduced_motion,
         }
     }
 }
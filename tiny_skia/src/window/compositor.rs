@@ -128,6 +128,25 @@ impl crate::graphics::Compositor for Compositor {
     ) -> Vec<u8> {
         screenshot(renderer, surface, viewport, background_color, overlay)
     }
+
+    fn screenshot_into<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+        buffer: &mut Vec<u8>,
+    ) {
+        screenshot_into(
+            renderer,
+            surface,
+            viewport,
+            background_color,
+            overlay,
+            buffer,
+        );
+    }
 }
 
 pub fn new<W: compositor::Window>(
@@ -217,18 +236,36 @@ pub fn screenshot<T: AsRef<str>>(
     background_color: Color,
     overlay: &[T],
 ) -> Vec<u8> {
+    let mut buffer = Vec::new();
+
+    screenshot_into(
+        renderer,
+        surface,
+        viewport,
+        background_color,
+        overlay,
+        &mut buffer,
+    );
+
+    buffer
+}
+
+pub fn screenshot_into<T: AsRef<str>>(
+    renderer: &mut Renderer,
+    surface: &mut Surface,
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+    buffer: &mut Vec<u8>,
+) {
     let size = viewport.physical_size();
 
-    let mut offscreen_buffer: Vec<u32> =
-        vec![0; size.width as usize * size.height as usize];
+    buffer.clear();
+    buffer.resize(size.width as usize * size.height as usize * 4, 0);
 
     renderer.draw(
-        &mut tiny_skia::PixmapMut::from_bytes(
-            bytemuck::cast_slice_mut(&mut offscreen_buffer),
-            size.width,
-            size.height,
-        )
-        .expect("Create offscreen pixel map"),
+        &mut tiny_skia::PixmapMut::from_bytes(buffer, size.width, size.height)
+            .expect("Create offscreen pixel map"),
         &mut surface.clip_mask,
         viewport,
         &[Rectangle::with_size(Size::new(
@@ -239,21 +276,12 @@ pub fn screenshot<T: AsRef<str>>(
         overlay,
     );
 
-    offscreen_buffer.iter().fold(
-        Vec::with_capacity(offscreen_buffer.len() * 4),
-        |mut acc, pixel| {
-            const A_MASK: u32 = 0xFF_00_00_00;
-            const R_MASK: u32 = 0x00_FF_00_00;
-            const G_MASK: u32 = 0x00_00_FF_00;
-            const B_MASK: u32 = 0x00_00_00_FF;
-
-            let a = ((A_MASK & pixel) >> 24) as u8;
-            let r = ((R_MASK & pixel) >> 16) as u8;
-            let g = ((G_MASK & pixel) >> 8) as u8;
-            let b = (B_MASK & pixel) as u8;
-
-            acc.extend([r, g, b, a]);
-            acc
-        },
-    )
+    // Pixels are drawn as native-endian `0xAARRGGBB` words
+    for pixel in buffer.chunks_exact_mut(4) {
+        let [a, r, g, b] =
+            u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]])
+                .to_be_bytes();
+
+        pixel.copy_from_slice(&[r, g, b, a]);
+    }
 }
@@ -347,6 +347,14 @@ impl graphics::Compositor for Compositor {
         );
     }
 
+    fn destroy(&mut self, renderer: Self::Renderer, surface: Self::Surface) {
+        drop(surface);
+        drop(renderer);
+
+        // Let the device reclaim the resources of the dropped surface
+        let _ = self.device.poll(wgpu::Maintain::Poll);
+    }
+
     fn fetch_information(&self) -> compositor::Information {
         let information = self.adapter.get_info();
 
@@ -377,6 +385,25 @@ impl graphics::Compositor for Compositor {
     ) -> Vec<u8> {
         screenshot(self, renderer, viewport, background_color, overlay)
     }
+
+    fn screenshot_into<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+        buffer: &mut Vec<u8>,
+    ) {
+        screenshot_into(
+            self,
+            renderer,
+            viewport,
+            background_color,
+            overlay,
+            buffer,
+        );
+    }
 }
 
 /// Renders the current surface to an offscreen buffer.
@@ -389,6 +416,30 @@ pub fn screenshot<T: AsRef<str>>(
     background_color: Color,
     overlay: &[T],
 ) -> Vec<u8> {
+    let mut buffer = Vec::new();
+
+    screenshot_into(
+        compositor,
+        renderer,
+        viewport,
+        background_color,
+        overlay,
+        &mut buffer,
+    );
+
+    buffer
+}
+
+/// Renders the current surface to an offscreen buffer, writing its bytes
+/// into the given buffer.
+pub fn screenshot_into<T: AsRef<str>>(
+    compositor: &mut Compositor,
+    renderer: &mut Renderer,
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+    buffer: &mut Vec<u8>,
+) {
     let dimensions = BufferDimensions::new(viewport.physical_size());
 
     let texture_extent = wgpu::Extent3d {
@@ -474,13 +525,14 @@ pub fn screenshot<T: AsRef<str>>(
 
     let mapped_buffer = slice.get_mapped_range();
 
-    mapped_buffer.chunks(dimensions.padded_bytes_per_row).fold(
-        vec![],
-        |mut acc, row| {
-            acc.extend(&row[..dimensions.unpadded_bytes_per_row]);
-            acc
-        },
-    )
+    buffer.clear();
+    buffer.reserve(
+        dimensions.unpadded_bytes_per_row * dimensions.height as usize,
+    );
+
+    for row in mapped_buffer.chunks(dimensions.padded_bytes_per_row) {
+        buffer.extend_from_slice(&row[..dimensions.unpadded_bytes_per_row]);
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
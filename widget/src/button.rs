@@ -344,7 +344,7 @@ where
         tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        renderer_style: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         viewport: &Rectangle,
@@ -397,6 +397,7 @@ where
             theme,
             &renderer::Style {
                 text_color: style.text_color,
+                ..*renderer_style
             },
             content_layout,
             cursor,
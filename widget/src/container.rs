@@ -76,6 +76,7 @@ pub struct Container<
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     clip: bool,
+    pass_through: bool,
     content: Element<'a, Message, Theme, Renderer>,
     class: Theme::Class<'a>,
 }
@@ -102,6 +103,7 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             clip: false,
+            pass_through: false,
             class: Theme::default(),
             content,
         }
@@ -211,6 +213,20 @@ where
         self
     }
 
+    /// Sets whether the [`Container`] should ignore the mouse and touch
+    /// interactions that start within its bounds.
+    ///
+    /// The ignored interactions are discarded; they only reach the windows
+    /// below when the [`Container`] covers its whole window, since only then
+    /// the platform can be told to let them pass through.
+    ///
+    /// This can be used to build shaped windows, like an overlay that only
+    /// accepts input in some of its parts.
+    pub fn pass_through(mut self, pass_through: bool) -> Self {
+        self.pass_through = pass_through;
+        self
+    }
+
     /// Sets the style of the [`Container`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -251,6 +267,17 @@ where
         self.content.as_widget().diff(tree);
     }
 
+    fn id(&self) -> Option<&widget::Id> {
+        // A container shares its tree with its content (see `tag`, `state`,
+        // and `diff`), so the id of the content also identifies that tree
+        // when the container has none of its own. Otherwise, wrapping an
+        // identified widget in a container would lose its state on reorder.
+        self.id
+            .as_ref()
+            .map(|id| &id.0)
+            .or_else(|| self.content.as_widget().id())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -284,6 +311,13 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        if self.pass_through {
+            operation.custom(
+                &mut widget::operation::PassThrough(layout.bounds()),
+                self.id.as_ref().map(|id| &id.0),
+            );
+        }
+
         operation.container(
             self.id.as_ref().map(|id| &id.0),
             layout.bounds(),
@@ -362,6 +396,7 @@ where
                     text_color: style
                         .text_color
                         .unwrap_or(renderer_style.text_color),
+                    ..*renderer_style
                 },
                 layout.children().next().unwrap(),
                 cursor,
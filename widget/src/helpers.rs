@@ -367,6 +367,7 @@ where
     use crate::core::layout::{self, Layout};
     use crate::core::mouse;
     use crate::core::renderer;
+    use crate::core::widget;
     use crate::core::widget::tree::{self, Tree};
     use crate::core::{Rectangle, Shell, Size};
 
@@ -395,6 +396,10 @@ where
             self.content.as_widget().diff(tree);
         }
 
+        fn id(&self) -> Option<&widget::Id> {
+            self.content.as_widget().id()
+        }
+
         fn size(&self) -> Size<Length> {
             self.content.as_widget().size()
         }
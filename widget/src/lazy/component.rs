@@ -264,6 +264,7 @@ where
     fn state(&self) -> tree::State {
         let state = Rc::new(RefCell::new(Some(Tree {
             tag: tree::Tag::of::<Tag<S>>(),
+            id: None,
             state: tree::State::new(S::default()),
             children: vec![Tree::empty()],
         })));
@@ -164,6 +164,7 @@ where
 
         let inherited_style = renderer::Style {
             text_color: style.text_color.unwrap_or(inherited_style.text_color),
+            ..*inherited_style
         };
 
         container::draw_background(renderer, &style, bounds);
@@ -22,7 +22,6 @@
 use crate::container;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
-use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
@@ -406,6 +405,10 @@ where
         tree.diff_children(std::slice::from_ref(&self.content));
     }
 
+    fn id(&self) -> Option<&widget::Id> {
+        self.id.as_ref().map(|id| &id.0)
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -750,14 +753,6 @@ where
             return event::Status::Captured;
         }
 
-        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
-            event
-        {
-            state.keyboard_modifiers = modifiers;
-
-            return event::Status::Ignored;
-        }
-
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 if cursor_over_scrollable.is_none() {
@@ -766,26 +761,14 @@ where
 
                 let delta = match delta {
                     mouse::ScrollDelta::Lines { x, y } => {
-                        let is_shift_pressed = state.keyboard_modifiers.shift();
-
-                        // macOS automatically inverts the axes when Shift is pressed
-                        let (x, y) =
-                            if cfg!(target_os = "macos") && is_shift_pressed {
-                                (y, x)
-                            } else {
-                                (x, y)
-                            };
-
-                        let is_vertical = match self.direction {
-                            Direction::Vertical(_) => true,
-                            Direction::Horizontal(_) => false,
-                            Direction::Both { .. } => !is_shift_pressed,
-                        };
-
-                        let movement = if is_vertical {
-                            Vector::new(x, y)
-                        } else {
-                            Vector::new(y, x)
+                        // Shift is handled by the shell (or the OS), which
+                        // turns vertical deltas into horizontal ones. A plain
+                        // vertical delta still scrolls horizontal scrollables.
+                        let movement = match self.direction {
+                            Direction::Horizontal(_) if x == 0.0 => {
+                                Vector::new(y, x)
+                            }
+                            _ => Vector::new(x, y),
                         };
 
                         // TODO: Configurable speed/friction (?)
@@ -1284,7 +1267,6 @@ struct State {
     y_scroller_grabbed_at: Option<f32>,
     offset_x: Offset,
     x_scroller_grabbed_at: Option<f32>,
-    keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
 }
@@ -1297,7 +1279,6 @@ impl Default for State {
             y_scroller_grabbed_at: None,
             offset_x: Offset::Absolute(0.0),
             x_scroller_grabbed_at: None,
-            keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
             last_scrolled: None,
         }
@@ -608,6 +608,10 @@ where
         }
     }
 
+    fn id(&self) -> Option<&widget::Id> {
+        self.id.as_ref().map(|id| &id.0)
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
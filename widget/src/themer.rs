@@ -5,7 +5,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::{self, Operation};
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
     Shell, Size, Vector, Widget,
@@ -86,6 +86,10 @@ where
         self.content.as_widget().diff(tree);
     }
 
+    fn id(&self) -> Option<&widget::Id> {
+        self.content.as_widget().id()
+    }
+
     fn size(&self) -> Size<Length> {
         self.content.as_widget().size()
     }
@@ -166,6 +170,7 @@ where
         let style = if let Some(text_color) = self.text_color {
             renderer::Style {
                 text_color: text_color(&theme),
+                ..*style
             }
         } else {
             *style
@@ -489,6 +489,7 @@ where
 
         let defaults = renderer::Style {
             text_color: style.text_color.unwrap_or(inherited_style.text_color),
+            ..*inherited_style
         };
 
         self.tooltip.as_widget().draw(
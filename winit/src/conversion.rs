@@ -4,6 +4,7 @@
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.13/runtime
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::theme;
 use crate::core::touch;
 use crate::core::window;
 use crate::core::{Event, Point, Size};
@@ -229,6 +230,7 @@ pub fn window_event(
                 location,
                 logical_key,
                 physical_key,
+                repeat,
                 ..
             } = event;
 
@@ -259,6 +261,7 @@ pub fn window_event(
                         modifiers,
                         location,
                         text,
+                        repeat,
                     }
                 }
                 winit::event::ElementState::Released => {
@@ -291,6 +294,9 @@ pub fn window_event(
         WindowEvent::HoveredFileCancelled => {
             Some(Event::Window(window::Event::FilesHoveredLeft))
         }
+        WindowEvent::ThemeChanged(theme) => Some(Event::Window(
+            window::Event::ThemeChanged(theme_mode(theme)),
+        )),
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(touch, scale_factor)))
         }
@@ -404,6 +410,25 @@ pub fn fullscreen(
     }
 }
 
+/// Converts a [`winit`] monitor handle to a [`window::Monitor`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(monitor: &winit::monitor::MonitorHandle) -> window::Monitor {
+    let scale_factor = monitor.scale_factor();
+    let position = monitor.position().to_logical::<f32>(scale_factor);
+    let physical_size = monitor.size();
+    let size = physical_size.to_logical::<f32>(scale_factor);
+
+    window::Monitor {
+        name: monitor.name(),
+        position: Point::new(position.x, position.y),
+        size: Size::new(size.width, size.height),
+        physical_size: Size::new(physical_size.width, physical_size.height),
+        scale_factor: scale_factor as f32,
+        refresh_rate: monitor.refresh_rate_millihertz(),
+    }
+}
+
 /// Converts a [`window::Mode`] to a visibility flag.
 pub fn visible(mode: window::Mode) -> bool {
     match mode {
@@ -422,6 +447,16 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Converts a [`winit`] window theme to a [`theme::Mode`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn theme_mode(theme: winit::window::Theme) -> theme::Mode {
+    match theme {
+        winit::window::Theme::Light => theme::Mode::Light,
+        winit::window::Theme::Dark => theme::Mode::Dark,
+    }
+}
+
 /// Converts a [`mouse::Interaction`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -9,7 +9,7 @@ use crate::core;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::time::Instant;
-use crate::core::widget::operation;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Color, Element, Point, Size, Theme};
 use crate::futures::futures::channel::mpsc;
@@ -23,7 +23,8 @@ use crate::graphics::{compositor, Compositor};
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::Debug;
 use crate::runtime::{self, Action, Task};
-use crate::{Clipboard, Error, Proxy, Settings};
+use crate::settings::{self, Settings};
+use crate::{Clipboard, Error, Proxy};
 
 use window_manager::WindowManager;
 
@@ -94,6 +95,22 @@ where
     /// runtime.
     fn update(&mut self, message: Self::Message) -> Task<Self::Message>;
 
+    /// Merges two adjacent __messages__ queued for [`update`](#tymethod.update)
+    /// into a single one, if possible.
+    ///
+    /// This can be used to avoid redundant updates when some interaction
+    /// produces messages at a high frequency (e.g. dragging a slider).
+    ///
+    /// By default, it returns `None` and no messages are merged.
+    #[allow(unused_variables)]
+    fn coalesce(
+        &self,
+        previous: &Self::Message,
+        next: &Self::Message,
+    ) -> Option<Self::Message> {
+        None
+    }
+
     /// Returns the widgets to display in the [`Program`] for the `window`.
     ///
     /// These widgets can produce __messages__ based on user interaction.
@@ -110,6 +127,15 @@ where
         theme.default_style()
     }
 
+    /// Returns the `Style` variation of the `Theme` for the given `window`.
+    ///
+    /// By default, it returns the [`style`](#method.style) of the `Theme`
+    /// for every window.
+    #[allow(unused_variables)]
+    fn style_for(&self, window: window::Id, theme: &Self::Theme) -> Appearance {
+        self.style(theme)
+    }
+
     /// Returns the event `Subscription` for the current state of the
     /// application.
     ///
@@ -146,6 +172,12 @@ pub struct Appearance {
 
     /// The default text [`Color`] of the application.
     pub text_color: Color,
+
+    /// The accent [`Color`] of the application, if any.
+    pub accent_color: Option<Color>,
+
+    /// The [`Color`] of selections in the application, if any.
+    pub selection_color: Option<Color>,
 }
 
 /// The default style of a [`Program`].
@@ -167,6 +199,8 @@ pub fn default(theme: &Theme) -> Appearance {
     Appearance {
         background_color: palette.background.base.color,
         text_color: palette.background.base.text,
+        accent_color: Some(palette.primary.base.color),
+        selection_color: Some(palette.primary.weak.color),
     }
 }
 /// Runs a [`Program`] with an executor, compositor, and the provided
@@ -234,6 +268,9 @@ where
         boot_receiver,
         event_receiver,
         control_sender,
+        settings.scrolling,
+        settings.suppress_key_repeats,
+        settings.reduced_motion,
         is_daemon,
     ));
 
@@ -293,6 +330,11 @@ where
                 graphics_settings,
             }) = self.boot.take()
             else {
+                self.process_event(
+                    event_loop,
+                    Event::EventLoopAwakened(winit::event::Event::Resumed),
+                );
+
                 return;
             };
 
@@ -321,6 +363,15 @@ where
                 self.canvas = window.canvas();
             }
 
+            // Discover the system fonts in the background while the
+            // compositor is being initialized
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = std::thread::Builder::new()
+                .name(String::from("iced_font_system"))
+                .spawn(|| {
+                    let _ = graphics::text::font_system();
+                });
+
             let finish_boot = async move {
                 let mut compositor =
                     C::new(graphics_settings, window.clone()).await?;
@@ -360,6 +411,20 @@ where
             }
         }
 
+        fn suspended(
+            &mut self,
+            event_loop: &winit::event_loop::ActiveEventLoop,
+        ) {
+            if self.boot.is_some() {
+                return;
+            }
+
+            self.process_event(
+                event_loop,
+                Event::EventLoopAwakened(winit::event::Event::Suspended),
+            );
+        }
+
         fn new_events(
             &mut self,
             event_loop: &winit::event_loop::ActiveEventLoop,
@@ -512,6 +577,8 @@ where
                             } => {
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
+                                let hide_on_close_request =
+                                    settings.hide_on_close_request;
 
                                 let visible = settings.visible;
 
@@ -538,9 +605,28 @@ where
 
                                 log::info!("Window attributes for id `{id:#?}`: {window_attributes:#?}");
 
-                                let window = event_loop
+                                let window = match event_loop
                                     .create_window(window_attributes)
-                                    .expect("Create window");
+                                {
+                                    Ok(window) => window,
+                                    Err(error) => {
+                                        log::error!("Error creating window `{id:?}`: {error}");
+
+                                        let error = runtime::window::Error::CreationFailed(
+                                            error.to_string(),
+                                        );
+
+                                        self.process_event(
+                                            event_loop,
+                                            Event::WindowCreationFailed {
+                                                error,
+                                                on_open,
+                                            },
+                                        );
+
+                                        continue;
+                                    }
+                                };
 
                                 #[cfg(target_arch = "wasm32")]
                                 {
@@ -594,6 +680,7 @@ where
                                         id,
                                         window,
                                         exit_on_close_request,
+                                        hide_on_close_request,
                                         make_visible: visible,
                                         on_open,
                                     },
@@ -602,6 +689,16 @@ where
                             Control::Exit => {
                                 event_loop.exit();
                             }
+                            Control::ListMonitors(channel) => {
+                                let _ = channel.send(
+                                    event_loop
+                                        .available_monitors()
+                                        .map(|monitor| {
+                                            conversion::monitor(&monitor)
+                                        })
+                                        .collect(),
+                                );
+                            }
                         },
                         _ => {
                             break;
@@ -643,8 +740,13 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: winit::window::Window,
         exit_on_close_request: bool,
+        hide_on_close_request: bool,
         make_visible: bool,
-        on_open: oneshot::Sender<window::Id>,
+        on_open: oneshot::Sender<Result<window::Id, runtime::window::Error>>,
+    },
+    WindowCreationFailed {
+        error: runtime::window::Error,
+        on_open: oneshot::Sender<Result<window::Id, runtime::window::Error>>,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -658,8 +760,9 @@ enum Control {
         settings: window::Settings,
         title: String,
         monitor: Option<winit::monitor::MonitorHandle>,
-        on_open: oneshot::Sender<window::Id>,
+        on_open: oneshot::Sender<Result<window::Id, runtime::window::Error>>,
     },
+    ListMonitors(oneshot::Sender<Vec<window::Monitor>>),
 }
 
 async fn run_instance<P, C>(
@@ -670,6 +773,9 @@ async fn run_instance<P, C>(
     boot: oneshot::Receiver<Boot<C>>,
     mut event_receiver: mpsc::UnboundedReceiver<Event<Action<P::Message>>>,
     mut control_sender: mpsc::UnboundedSender<Control>,
+    scrolling: settings::Scrolling,
+    suppress_key_repeats: bool,
+    reduced_motion: bool,
     is_daemon: bool,
 ) where
     P: Program + 'static,
@@ -681,6 +787,8 @@ async fn run_instance<P, C>(
 
     let Boot { mut compositor } = boot.await.expect("Receive boot");
 
+    core::animation::set_reduced_motion(reduced_motion);
+
     let mut window_manager = WindowManager::new();
     let mut is_window_opening = !is_daemon;
 
@@ -691,6 +799,16 @@ async fn run_instance<P, C>(
     let mut ui_caches = FxHashMap::default();
     let mut user_interfaces = ManuallyDrop::new(FxHashMap::default());
     let mut clipboard = Clipboard::unconnected();
+    let mut frame = Instant::now();
+
+    #[cfg(feature = "event-log")]
+    let mut event_log = std::env::var_os("ICED_EVENT_LOG").and_then(|path| {
+        runtime::event_log::Recorder::create(&path)
+            .map_err(|error| {
+                log::error!("Error creating event log {path:?}: {error}");
+            })
+            .ok()
+    });
 
     debug.startup_finished();
 
@@ -711,6 +829,7 @@ async fn run_instance<P, C>(
                 id,
                 window,
                 exit_on_close_request,
+                hide_on_close_request,
                 make_visible,
                 on_open,
             } => {
@@ -720,21 +839,23 @@ async fn run_instance<P, C>(
                     &program,
                     &mut compositor,
                     exit_on_close_request,
+                    hide_on_close_request,
                 );
 
                 let logical_size = window.state.logical_size();
 
-                let _ = user_interfaces.insert(
+                let mut ui = build_user_interface(
+                    &program,
+                    user_interface::Cache::default(),
+                    &mut window.renderer,
+                    logical_size,
+                    &mut debug,
                     id,
-                    build_user_interface(
-                        &program,
-                        user_interface::Cache::default(),
-                        &mut window.renderer,
-                        logical_size,
-                        &mut debug,
-                        id,
-                    ),
                 );
+
+                window.update_pass_through(&mut ui);
+
+                let _ = user_interfaces.insert(id, ui);
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
 
                 if make_visible {
@@ -753,17 +874,34 @@ async fn run_instance<P, C>(
                     clipboard = Clipboard::connect(window.raw.clone());
                 }
 
-                let _ = on_open.send(id);
+                let _ = on_open.send(Ok(id));
+                is_window_opening = false;
+            }
+            Event::WindowCreationFailed { error, on_open } => {
+                let _ = on_open.send(Err(error));
                 is_window_opening = false;
+
+                if !is_daemon && window_manager.is_empty() {
+                    control_sender
+                        .start_send(Control::Exit)
+                        .expect("Send control action");
+                }
             }
             Event::EventLoopAwakened(event) => {
                 match event {
-                    event::Event::NewEvents(
-                        event::StartCause::Init
-                        | event::StartCause::ResumeTimeReached { .. },
-                    ) => {
-                        for (_id, window) in window_manager.iter_mut() {
-                            window.raw.request_redraw();
+                    event::Event::NewEvents(cause) => {
+                        // All the windows redrawn during the same iteration
+                        // of the event loop share the same frame timestamp
+                        frame = Instant::now();
+
+                        if matches!(
+                            cause,
+                            event::StartCause::Init
+                                | event::StartCause::ResumeTimeReached { .. }
+                        ) {
+                            for (_id, window) in window_manager.iter_mut() {
+                                window.raw.request_redraw();
+                            }
                         }
                     }
                     event::Event::PlatformSpecific(
@@ -779,6 +917,38 @@ async fn run_instance<P, C>(
                             ),
                         );
                     }
+                    event::Event::Suspended => {
+                        for (id, window) in window_manager.iter_mut() {
+                            window.surface = None;
+
+                            events.push((
+                                id,
+                                core::Event::Window(window::Event::Suspended),
+                            ));
+                        }
+                    }
+                    event::Event::Resumed => {
+                        for (id, window) in window_manager.iter_mut() {
+                            if window.surface.is_none() {
+                                let physical_size =
+                                    window.state.physical_size();
+
+                                window.surface =
+                                    Some(compositor.create_surface(
+                                        window.raw.clone(),
+                                        physical_size.width,
+                                        physical_size.height,
+                                    ));
+                            }
+
+                            events.push((
+                                id,
+                                core::Event::Window(window::Event::Resumed),
+                            ));
+
+                            window.raw.request_redraw();
+                        }
+                    }
                     event::Event::UserEvent(action) => {
                         run_action(
                             action,
@@ -807,13 +977,19 @@ async fn run_instance<P, C>(
                             continue;
                         };
 
+                        // The surface is gone while the application
+                        // is suspended
+                        if window.surface.is_none() {
+                            continue;
+                        }
+
                         // TODO: Avoid redrawing all the time by forcing widgets to
                         // request redraws on state changes
                         //
                         // Then, we can use the `interface_state` here to decide if a redraw
                         // is needed right away, or simply wait until a specific time.
                         let redraw_event = core::Event::Window(
-                            window::Event::RedrawRequested(Instant::now()),
+                            window::Event::RedrawRequested(frame),
                         );
 
                         let cursor = window.state.cursor();
@@ -822,7 +998,7 @@ async fn run_instance<P, C>(
                             .get_mut(&id)
                             .expect("Get user interface");
 
-                        let (ui_state, _) = ui.update(
+                        let (ui_state, statuses) = ui.update(
                             &[redraw_event.clone()],
                             cursor,
                             &mut window.renderer,
@@ -830,12 +1006,18 @@ async fn run_instance<P, C>(
                             &mut messages,
                         );
 
+                        if has_changed(&ui_state, &statuses) {
+                            window.update_pass_through(ui);
+                        }
+
                         debug.draw_started();
                         let new_mouse_interaction = ui.draw(
                             &mut window.renderer,
                             window.state.theme(),
                             &renderer::Style {
                                 text_color: window.state.text_color(),
+                                accent_color: window.state.accent_color(),
+                                selection_color: window.state.selection_color(),
                             },
                             cursor,
                         );
@@ -863,7 +1045,9 @@ async fn run_instance<P, C>(
                                     redraw_request: Some(redraw_request),
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
-                                        window.raw.request_redraw();
+                                        if !window.state.is_occluded() {
+                                            window.raw.request_redraw();
+                                        }
 
                                         ControlFlow::Wait
                                     }
@@ -892,10 +1076,12 @@ async fn run_instance<P, C>(
                                 .remove(&id)
                                 .expect("Remove user interface");
 
-                            let _ = user_interfaces.insert(
-                                id,
-                                ui.relayout(logical_size, &mut window.renderer),
-                            );
+                            let mut ui =
+                                ui.relayout(logical_size, &mut window.renderer);
+
+                            window.update_pass_through(&mut ui);
+
+                            let _ = user_interfaces.insert(id, ui);
                             debug.layout_finished();
 
                             debug.draw_started();
@@ -907,6 +1093,12 @@ async fn run_instance<P, C>(
                                     window.state.theme(),
                                     &renderer::Style {
                                         text_color: window.state.text_color(),
+                                        accent_color: window
+                                            .state
+                                            .accent_color(),
+                                        selection_color: window
+                                            .state
+                                            .selection_color(),
                                     },
                                     window.state.cursor(),
                                 );
@@ -925,7 +1117,7 @@ async fn run_instance<P, C>(
                             }
 
                             compositor.configure_surface(
-                                &mut window.surface,
+                                window.surface.as_mut().expect("Get surface"),
                                 physical_size.width,
                                 physical_size.height,
                             );
@@ -935,9 +1127,12 @@ async fn run_instance<P, C>(
                         }
 
                         debug.render_started();
+                        let surface =
+                            window.surface.as_mut().expect("Get surface");
+
                         match compositor.present(
                             &mut window.renderer,
-                            &mut window.surface,
+                            surface,
                             window.state.viewport(),
                             window.state.background_color(),
                             &debug.overlay(),
@@ -987,16 +1182,50 @@ async fn run_instance<P, C>(
                             continue;
                         }
 
+                        if let winit::event::WindowEvent::ThemeChanged(theme) =
+                            window_event
+                        {
+                            let mode = conversion::theme_mode(theme);
+
+                            if core::theme::Mode::system() != mode {
+                                core::theme::Mode::set_system(mode);
+
+                                for (id, window) in window_manager.iter_mut() {
+                                    window.state.synchronize(
+                                        &program,
+                                        id,
+                                        &window.raw,
+                                    );
+
+                                    window.raw.request_redraw();
+                                }
+                            }
+                        }
+
                         let Some((id, window)) =
                             window_manager.get_mut_alias(window_id)
                         else {
                             continue;
                         };
 
-                        if matches!(
+                        if let winit::event::WindowEvent::ActivationTokenDone {
+                            serial,
+                            token,
+                        } = window_event
+                        {
+                            window.finish_activation(serial, token);
+                            continue;
+                        }
+
+                        let is_close_request = matches!(
                             window_event,
                             winit::event::WindowEvent::CloseRequested
-                        ) && window.exit_on_close_request
+                        );
+
+                        if is_close_request && window.hide_on_close_request {
+                            window.raw.set_visible(false);
+                        } else if is_close_request
+                            && window.exit_on_close_request
                         {
                             run_action(
                                 Action::Window(runtime::window::Action::Close(
@@ -1021,20 +1250,97 @@ async fn run_instance<P, C>(
                                 &mut debug,
                             );
 
+                            // Resume drawing once the window is visible again
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::Occluded(false)
+                            ) {
+                                window.raw.request_redraw();
+                            }
+
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
+                                let event = match event {
+                                    core::Event::Mouse(
+                                        mouse::Event::WheelScrolled { delta },
+                                    ) => core::Event::Mouse(
+                                        mouse::Event::WheelScrolled {
+                                            delta: scrolling.apply(
+                                                delta,
+                                                conversion::modifiers(
+                                                    window.state.modifiers(),
+                                                ),
+                                            ),
+                                        },
+                                    ),
+                                    event => event,
+                                };
+
+                                if !window.is_within_input_region(&event) {
+                                    continue;
+                                }
+
+                                if suppress_key_repeats
+                                    && matches!(
+                                        event,
+                                        core::Event::Keyboard(
+                                            core::keyboard::Event::KeyPressed {
+                                                repeat: true,
+                                                ..
+                                            }
+                                        )
+                                    )
+                                {
+                                    continue;
+                                }
+
+                                if let Some(recorder) = &mut window.recorder {
+                                    recorder.record(&event, |position| {
+                                        let ui =
+                                            user_interfaces.get_mut(&id)?;
+                                        let target = ui.hit_test(
+                                            &window.renderer,
+                                            position,
+                                        )?;
+                                        let bounds = ui.bounds_of(
+                                            &window.renderer,
+                                            target.clone(),
+                                        )?;
+
+                                        Some((target, bounds))
+                                    });
+                                }
+
                                 events.push((id, event));
                             }
                         }
                     }
                     event::Event::AboutToWait => {
+                        for (_id, window) in window_manager.iter_mut() {
+                            window.expire_activations();
+                        }
+
                         if events.is_empty() && messages.is_empty() {
                             continue;
                         }
 
+                        #[cfg(feature = "event-log")]
+                        if let Some(recorder) = &mut event_log {
+                            let result = events
+                                .iter()
+                                .try_for_each(|(id, event)| {
+                                    recorder.record(*id, event)
+                                })
+                                .and_then(|()| recorder.flush());
+
+                            if let Err(error) = result {
+                                log::error!("Error recording events: {error}");
+                            }
+                        }
+
                         debug.event_processing_started();
                         let mut uis_stale = false;
 
@@ -1054,16 +1360,21 @@ async fn run_instance<P, C>(
                                 continue;
                             }
 
-                            let (ui_state, statuses) = user_interfaces
+                            let ui = user_interfaces
                                 .get_mut(&id)
-                                .expect("Get user interface")
-                                .update(
-                                    &window_events,
-                                    window.state.cursor(),
-                                    &mut window.renderer,
-                                    &mut clipboard,
-                                    &mut messages,
-                                );
+                                .expect("Get user interface");
+
+                            let (ui_state, statuses) = ui.update(
+                                &window_events,
+                                window.state.cursor(),
+                                &mut window.renderer,
+                                &mut clipboard,
+                                &mut messages,
+                            );
+
+                            if has_changed(&ui_state, &statuses) {
+                                window.update_pass_through(ui);
+                            }
 
                             window.raw.request_redraw();
 
@@ -1123,7 +1434,9 @@ async fn run_instance<P, C>(
                                     &window.raw,
                                 );
 
-                                window.raw.request_redraw();
+                                if !window.state.is_occluded() {
+                                    window.raw.request_redraw();
+                                }
                             }
 
                             user_interfaces =
@@ -1180,6 +1493,8 @@ fn update<P: Program, E: Executor>(
 ) where
     P::Theme: DefaultStyle,
 {
+    coalesce(messages, |previous, next| program.coalesce(previous, next));
+
     for message in messages.drain(..) {
         debug.log_message(&message);
         debug.update_started();
@@ -1196,6 +1511,18 @@ fn update<P: Program, E: Executor>(
     runtime.track(subscription::into_recipes(subscription.map(Action::Output)));
 }
 
+/// Merges adjacent messages with the given function, keeping their order.
+fn coalesce<T>(messages: &mut Vec<T>, f: impl Fn(&T, &T) -> Option<T>) {
+    messages.dedup_by(|next, previous| {
+        if let Some(message) = f(previous, next) {
+            *previous = message;
+            true
+        } else {
+            false
+        }
+    });
+}
+
 fn run_action<P, C>(
     action: Action<P::Message>,
     program: &P,
@@ -1262,6 +1589,10 @@ fn run_action<P, C>(
                             .unwrap_or_else(Clipboard::unconnected);
                     }
 
+                    if let Some(surface) = window.surface {
+                        compositor.destroy(window.renderer, surface);
+                    }
+
                     events.push((
                         id,
                         core::Event::Window(core::window::Event::Closed),
@@ -1295,6 +1626,63 @@ fn run_action<P, C>(
                     );
                 }
             }
+            window::Action::ResizeToContent(id) => {
+                let (Some(window), Some(ui)) =
+                    (window_manager.get_mut(id), interfaces.get_mut(&id))
+                else {
+                    return;
+                };
+
+                let scale_factor = window.state.viewport().scale_factor();
+
+                let max = window
+                    .raw
+                    .current_monitor()
+                    .map(|monitor| {
+                        let size = monitor.size();
+
+                        Size::new(
+                            (f64::from(size.width) / scale_factor) as f32,
+                            (f64::from(size.height) / scale_factor) as f32,
+                        )
+                    })
+                    .unwrap_or(Size::INFINITY);
+
+                let size = ui.measure(&window.renderer, max);
+
+                let _ =
+                    window.raw.request_inner_size(winit::dpi::PhysicalSize {
+                        width: (f64::from(size.width) * scale_factor).ceil()
+                            as u32,
+                        height: (f64::from(size.height) * scale_factor).ceil()
+                            as u32,
+                    });
+            }
+            window::Action::SetMinSize(id, size) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_min_inner_size(size.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                    }));
+                }
+            }
+            window::Action::SetMaxSize(id, size) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_max_inner_size(size.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                    }));
+                }
+            }
+            window::Action::SetResizable(id, resizable) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_resizable(resizable);
+                }
+            }
             window::Action::GetSize(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let size = window
@@ -1348,6 +1736,105 @@ fn run_action<P, C>(
                     let _ = channel.send(scale_factor as f32);
                 }
             }
+            window::Action::GetMonitor(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let monitor = window
+                        .raw
+                        .current_monitor()
+                        .as_ref()
+                        .map(conversion::monitor);
+
+                    let _ = channel.send(monitor);
+                }
+            }
+            window::Action::GetMonitors(channel) => {
+                control_sender
+                    .start_send(Control::ListMonitors(channel))
+                    .expect("Send control action");
+            }
+            window::Action::GetGeometry(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let _ = channel.send(window.geometry());
+                }
+            }
+            window::Action::SetGeometry(id, geometry) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.set_geometry(geometry);
+                }
+            }
+            window::Action::HitTest(id, point, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    if let Some(ui) = interfaces.get_mut(&id) {
+                        let _ =
+                            channel.send(ui.hit_test(&window.renderer, point));
+                    }
+                }
+            }
+            window::Action::DispatchEvent(id, event) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.state.update_cursor(&event);
+
+                    events.push((id, event));
+                }
+            }
+            window::Action::StartRecording(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.recorder =
+                        Some(runtime::automation::Recorder::new(id));
+                }
+            }
+            window::Action::StopRecording(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let _ = channel.send(
+                        window
+                            .recorder
+                            .take()
+                            .map(runtime::automation::Recorder::finish)
+                            .unwrap_or_default(),
+                    );
+                }
+            }
+            window::Action::Operate(id, operation) => {
+                let mut current_operation = Some(operation);
+
+                while let Some(mut operation) = current_operation.take() {
+                    if let (Some(window), Some(ui)) =
+                        (window_manager.get_mut(id), interfaces.get_mut(&id))
+                    {
+                        ui.operate(&window.renderer, operation.as_mut());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(()) => {}
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+            }
+            window::Action::SetScaleFactor(id, scale_factor) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.state.set_scale_factor_override(
+                        &window.raw,
+                        Some(f64::from(scale_factor)),
+                    );
+                }
+            }
+            window::Action::ResetScaleFactor(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.state.set_scale_factor_override(&window.raw, None);
+                }
+            }
+            window::Action::ZoomIn(channel) => {
+                let _ = channel.send(window_manager.zoom_in() as f32);
+            }
+            window::Action::ZoomOut(channel) => {
+                let _ = channel.send(window_manager.zoom_out() as f32);
+            }
+            window::Action::ZoomReset(channel) => {
+                let _ = channel.send(window_manager.zoom_reset() as f32);
+            }
             window::Action::Move(id, position) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_outer_position(
@@ -1358,6 +1845,27 @@ fn run_action<P, C>(
                     );
                 }
             }
+            window::Action::MoveRelative(id, anchor, offset) => {
+                let Some(anchor) = window_manager.get(anchor) else {
+                    return;
+                };
+
+                let Ok(anchor_position) = anchor.raw.outer_position() else {
+                    return;
+                };
+
+                let anchor_position: winit::dpi::LogicalPosition<f32> =
+                    anchor_position.to_logical(anchor.raw.scale_factor());
+
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_outer_position(
+                        winit::dpi::LogicalPosition {
+                            x: anchor_position.x + offset.x,
+                            y: anchor_position.y + offset.y,
+                        },
+                    );
+                }
+            }
             window::Action::ChangeMode(id, mode) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_visible(conversion::visible(mode));
@@ -1367,6 +1875,11 @@ fn run_action<P, C>(
                     ));
                 }
             }
+            window::Action::SetTitle(id, title) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_title(&title);
+                }
+            }
             window::Action::ChangeIcon(id, icon) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_window_icon(conversion::icon(icon));
@@ -1405,6 +1918,12 @@ fn run_action<P, C>(
                     window.raw.focus_window();
                 }
             }
+            window::Action::Show(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_visible(true);
+                    window.raw.focus_window();
+                }
+            }
             window::Action::ChangeLevel(id, level) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window
@@ -1441,23 +1960,69 @@ fn run_action<P, C>(
                     f(handle);
                 }
             }
+            window::Action::SetBackgroundColor(id, color) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.state.set_background_color(color);
+                    window.raw.request_redraw();
+                }
+            }
+            window::Action::SpawnWithActivationToken(id, command, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.spawn_with_activation_token(command, channel);
+                }
+            }
+            window::Action::RequestActivationToken(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.request_activation_token(channel);
+                }
+            }
             window::Action::Screenshot(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
-                    let bytes = compositor.screenshot(
-                        &mut window.renderer,
-                        &mut window.surface,
-                        window.state.viewport(),
-                        window.state.background_color(),
-                        &debug.overlay(),
-                    );
+                    if let Some(surface) = &mut window.surface {
+                        let bytes = compositor.screenshot(
+                            &mut window.renderer,
+                            surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        );
 
-                    let _ = channel.send(window::Screenshot::new(
-                        bytes,
-                        window.state.physical_size(),
-                        window.state.viewport().scale_factor(),
-                    ));
+                        let _ = channel.send(window::Screenshot::new(
+                            bytes,
+                            window.state.physical_size(),
+                            window.state.viewport().scale_factor(),
+                        ));
+                    }
                 }
             }
+            window::Action::ScreenshotInto(id, mut buffer, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    if let Some(surface) = &mut window.surface {
+                        compositor.screenshot_into(
+                            &mut window.renderer,
+                            surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                            &mut buffer,
+                        );
+
+                        let _ = channel.send(window::Screenshot::new(
+                            buffer,
+                            window.state.physical_size(),
+                            window.state.viewport().scale_factor(),
+                        ));
+                    }
+                }
+            }
+            window::Action::SetInputRegion(id, region) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.set_input_region(region);
+                }
+            }
+            window::Action::SetOpaqueRegion(_id, _region) => {
+                // `winit` does not expose the opaque region of a surface
+            }
             window::Action::EnableMousePassthrough(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let _ = window.raw.set_cursor_hittest(false);
@@ -1474,10 +2039,16 @@ fn run_action<P, C>(
                 #[cfg(feature = "system")]
                 {
                     let graphics_info = compositor.fetch_information();
+                    let session_type =
+                        window_manager.first().and_then(|window| {
+                            crate::system::session_type(&window.raw)
+                        });
 
                     let _ = std::thread::spawn(move || {
-                        let information =
-                            crate::system::information(graphics_info);
+                        let information = crate::system::information(
+                            graphics_info,
+                            session_type,
+                        );
 
                         let _ = _channel.send(information);
                     });
@@ -1533,21 +2104,38 @@ where
         .filter_map(|(id, cache)| {
             let window = window_manager.get_mut(id)?;
 
-            Some((
+            let mut ui = build_user_interface(
+                program,
+                cache,
+                &mut window.renderer,
+                window.state.logical_size(),
+                debug,
                 id,
-                build_user_interface(
-                    program,
-                    cache,
-                    &mut window.renderer,
-                    window.state.logical_size(),
-                    debug,
-                    id,
-                ),
-            ))
+            );
+
+            window.update_pass_through(&mut ui);
+
+            Some((id, ui))
         })
         .collect()
 }
 
+/// Returns whether an update of a [`UserInterface`] may have changed its
+/// layout or scrolled some of its contents.
+fn has_changed(
+    state: &user_interface::State,
+    statuses: &[core::event::Status],
+) -> bool {
+    matches!(
+        state,
+        user_interface::State::Updated {
+            redraw_request: Some(_)
+        }
+    ) || statuses
+        .iter()
+        .any(|status| matches!(status, core::event::Status::Captured))
+}
+
 /// Returns true if the provided event should cause a [`Program`] to
 /// exit.
 pub fn user_force_quit(
@@ -1568,3 +2156,61 @@ pub fn user_force_quit(
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Dragged(u32),
+        Released,
+    }
+
+    fn merge_drags(previous: &Message, next: &Message) -> Option<Message> {
+        match (previous, next) {
+            (Message::Dragged(_), Message::Dragged(value)) => {
+                Some(Message::Dragged(*value))
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn coalesce_merges_adjacent_messages() {
+        let mut messages = vec![
+            Message::Dragged(1),
+            Message::Dragged(2),
+            Message::Dragged(3),
+        ];
+
+        coalesce(&mut messages, merge_drags);
+
+        assert_eq!(messages, [Message::Dragged(3)]);
+    }
+
+    #[test]
+    fn coalesce_keeps_order_of_non_adjacent_messages() {
+        let mut messages = vec![
+            Message::Dragged(1),
+            Message::Dragged(2),
+            Message::Released,
+            Message::Dragged(3),
+            Message::Released,
+            Message::Released,
+        ];
+
+        coalesce(&mut messages, merge_drags);
+
+        assert_eq!(
+            messages,
+            [
+                Message::Dragged(2),
+                Message::Released,
+                Message::Dragged(3),
+                Message::Released,
+                Message::Released,
+            ]
+        );
+    }
+}
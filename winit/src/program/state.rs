@@ -1,5 +1,5 @@
 use crate::conversion;
-use crate::core::{mouse, window};
+use crate::core::{self, mouse, touch, window};
 use crate::core::{Color, Size};
 use crate::graphics::Viewport;
 use crate::program::{self, Program};
@@ -15,12 +15,16 @@ where
 {
     title: String,
     scale_factor: f64,
+    scale_factor_override: Option<f64>,
+    zoom: f64,
     viewport: Viewport,
     viewport_version: u64,
     cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
     modifiers: winit::keyboard::ModifiersState,
+    is_occluded: bool,
     theme: P::Theme,
     appearance: program::Appearance,
+    background_color: Option<Color>,
 }
 
 impl<P: Program> Debug for State<P>
@@ -31,10 +35,13 @@ where
         f.debug_struct("multi_window::State")
             .field("title", &self.title)
             .field("scale_factor", &self.scale_factor)
+            .field("scale_factor_override", &self.scale_factor_override)
+            .field("zoom", &self.zoom)
             .field("viewport", &self.viewport)
             .field("viewport_version", &self.viewport_version)
             .field("cursor_position", &self.cursor_position)
             .field("appearance", &self.appearance)
+            .field("background_color", &self.background_color)
             .finish()
     }
 }
@@ -48,30 +55,35 @@ where
         application: &P,
         window_id: window::Id,
         window: &Window,
+        zoom: f64,
     ) -> Self {
         let title = application.title(window_id);
         let scale_factor = application.scale_factor(window_id);
         let theme = application.theme(window_id);
-        let appearance = application.style(&theme);
+        let appearance = application.style_for(window_id, &theme);
 
         let viewport = {
             let physical_size = window.inner_size();
 
             Viewport::with_physical_size(
                 Size::new(physical_size.width, physical_size.height),
-                window.scale_factor() * scale_factor,
+                window.scale_factor() * scale_factor * zoom,
             )
         };
 
         Self {
             title,
             scale_factor,
+            scale_factor_override: None,
+            zoom,
             viewport,
             viewport_version: 0,
             cursor_position: None,
             modifiers: winit::keyboard::ModifiersState::default(),
+            is_occluded: false,
             theme,
             appearance,
+            background_color: None,
         }
     }
 
@@ -115,19 +127,65 @@ where
             .unwrap_or(mouse::Cursor::Unavailable)
     }
 
+    /// Updates the cursor position of the [`State`] with an [`Event`] that was
+    /// not produced by the windowing system.
+    ///
+    /// [`Event`]: core::Event
+    pub fn update_cursor(&mut self, event: &core::Event) {
+        match event {
+            core::Event::Mouse(mouse::Event::CursorMoved { position })
+            | core::Event::Touch(
+                touch::Event::FingerPressed { position, .. }
+                | touch::Event::FingerMoved { position, .. },
+            ) => {
+                let scale_factor = self.viewport.scale_factor();
+
+                self.cursor_position = Some(winit::dpi::PhysicalPosition::new(
+                    f64::from(position.x) * scale_factor,
+                    f64::from(position.y) * scale_factor,
+                ));
+            }
+            core::Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor_position = None;
+            }
+            _ => {}
+        }
+    }
+
     /// Returns the current keyboard modifiers of the [`State`].
     pub fn modifiers(&self) -> winit::keyboard::ModifiersState {
         self.modifiers
     }
 
+    /// Returns whether the window of the [`State`] is currently occluded.
+    ///
+    /// An occluded window is not visible to the user and, therefore, it
+    /// does not need to produce any new frames.
+    pub fn is_occluded(&self) -> bool {
+        self.is_occluded
+    }
+
     /// Returns the current theme of the [`State`].
     pub fn theme(&self) -> &P::Theme {
         &self.theme
     }
 
     /// Returns the current background [`Color`] of the [`State`].
+    ///
+    /// This is the overridden background [`Color`], if any; otherwise,
+    /// the one given by the current [`program::Appearance`].
     pub fn background_color(&self) -> Color {
-        self.appearance.background_color
+        self.background_color
+            .unwrap_or(self.appearance.background_color)
+    }
+
+    /// Overrides the background [`Color`] of the [`State`], regardless of its
+    /// [`program::Appearance`].
+    ///
+    /// Providing `None` will restore the background [`Color`] of the current
+    /// [`program::Appearance`].
+    pub fn set_background_color(&mut self, color: Option<Color>) {
+        self.background_color = color;
     }
 
     /// Returns the current text [`Color`] of the [`State`].
@@ -135,6 +193,74 @@ where
         self.appearance.text_color
     }
 
+    /// Returns the current accent [`Color`] of the [`State`], if any.
+    pub fn accent_color(&self) -> Option<Color> {
+        self.appearance.accent_color
+    }
+
+    /// Returns the current selection [`Color`] of the [`State`], if any.
+    pub fn selection_color(&self) -> Option<Color> {
+        self.appearance.selection_color
+    }
+
+    /// Overrides the scale factor provided by the windowing system for the
+    /// window of the [`State`].
+    ///
+    /// The scale factor of the [`Program`] is still applied on top of it.
+    /// Providing `None` restores the scale factor of the windowing system.
+    /// Scale factors that are not finite or not positive are ignored.
+    pub fn set_scale_factor_override(
+        &mut self,
+        window: &Window,
+        scale_factor: Option<f64>,
+    ) {
+        if let Some(scale_factor) = scale_factor {
+            if !scale_factor.is_finite() || scale_factor <= 0.0 {
+                log::warn!("Ignoring invalid scale factor: {scale_factor}");
+                return;
+            }
+        }
+
+        if self.scale_factor_override == scale_factor {
+            return;
+        }
+
+        self.scale_factor_override = scale_factor;
+
+        self.viewport = Viewport::with_physical_size(
+            self.viewport.physical_size(),
+            self.window_scale_factor(window) * self.scale_factor * self.zoom,
+        );
+
+        self.viewport_version = self.viewport_version.wrapping_add(1);
+
+        window.request_redraw();
+    }
+
+    /// Sets the application-wide zoom factor applied to the window of the
+    /// [`State`].
+    pub fn set_zoom(&mut self, window: &Window, zoom: f64) {
+        if self.zoom == zoom {
+            return;
+        }
+
+        self.zoom = zoom;
+
+        self.viewport = Viewport::with_physical_size(
+            self.viewport.physical_size(),
+            self.window_scale_factor(window) * self.scale_factor * zoom,
+        );
+
+        self.viewport_version = self.viewport_version.wrapping_add(1);
+
+        window.request_redraw();
+    }
+
+    fn window_scale_factor(&self, window: &Window) -> f64 {
+        self.scale_factor_override
+            .unwrap_or_else(|| window.scale_factor())
+    }
+
     /// Processes the provided window event and updates the [`State`] accordingly.
     pub fn update(
         &mut self,
@@ -148,7 +274,9 @@ where
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    window.scale_factor() * self.scale_factor,
+                    self.window_scale_factor(window)
+                        * self.scale_factor
+                        * self.zoom,
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
@@ -161,7 +289,9 @@ where
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    new_scale_factor * self.scale_factor,
+                    self.scale_factor_override.unwrap_or(*new_scale_factor)
+                        * self.scale_factor
+                        * self.zoom,
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
@@ -178,6 +308,9 @@ where
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = new_modifiers.state();
             }
+            WindowEvent::Occluded(is_occluded) => {
+                self.is_occluded = *is_occluded;
+            }
             #[cfg(feature = "debug")]
             WindowEvent::KeyboardInput {
                 event:
@@ -225,7 +358,7 @@ where
         {
             self.viewport = Viewport::with_physical_size(
                 Size::new(new_size.width, new_size.height),
-                window.scale_factor() * new_scale_factor,
+                self.window_scale_factor(window) * new_scale_factor * self.zoom,
             );
             self.viewport_version = self.viewport_version.wrapping_add(1);
 
@@ -234,6 +367,6 @@ where
 
         // Update theme and appearance
         self.theme = application.theme(window_id);
-        self.appearance = application.style(&self.theme);
+        self.appearance = application.style_for(window_id, &self.theme);
     }
 }
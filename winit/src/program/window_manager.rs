@@ -1,12 +1,28 @@
+use crate::core;
 use crate::core::mouse;
-use crate::core::window::Id;
-use crate::core::{Point, Size};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::window::{Geometry, Id};
+use crate::core::{Point, Rectangle, Size};
+use crate::futures::futures::channel::oneshot;
 use crate::graphics::Compositor;
 use crate::program::{DefaultStyle, Program, State};
+use crate::runtime::automation;
+use crate::runtime::user_interface::UserInterface;
 
 use std::collections::BTreeMap;
+use std::io;
+use std::process::{Child, Command};
 use std::sync::Arc;
+use winit::event_loop::AsyncRequestSerial;
 use winit::monitor::MonitorHandle;
+use winit::window::ActivationToken;
+
+const ZOOM_STEP: f64 = 1.1;
+const MIN_ZOOM: f64 = 0.3;
+const MAX_ZOOM: f64 = 5.0;
+const ACTIVATION_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[allow(missing_debug_implementations)]
 pub struct WindowManager<P, C>
@@ -17,6 +33,7 @@ where
 {
     aliases: BTreeMap<winit::window::WindowId, Id>,
     entries: BTreeMap<Id, Window<P, C>>,
+    zoom: f64,
 }
 
 impl<P, C> WindowManager<P, C>
@@ -29,6 +46,7 @@ where
         Self {
             aliases: BTreeMap::new(),
             entries: BTreeMap::new(),
+            zoom: 1.0,
         }
     }
 
@@ -39,8 +57,9 @@ where
         application: &P,
         compositor: &mut C,
         exit_on_close_request: bool,
+        hide_on_close_request: bool,
     ) -> &mut Window<P, C> {
-        let state = State::new(application, id, &window);
+        let state = State::new(application, id, &window, self.zoom);
         let viewport_version = state.viewport_version();
         let physical_size = state.physical_size();
         let surface = compositor.create_surface(
@@ -59,9 +78,14 @@ where
                 state,
                 viewport_version,
                 exit_on_close_request,
-                surface,
+                hide_on_close_request,
+                surface: Some(surface),
                 renderer,
+                recorder: None,
+                input_region: None,
+                pass_through: Vec::new(),
                 mouse_interaction: mouse::Interaction::None,
+                pending_activations: Vec::new(),
             },
         );
 
@@ -105,10 +129,37 @@ where
         self.entries.values().last()?.raw.current_monitor()
     }
 
+    pub fn zoom_in(&mut self) -> f64 {
+        self.set_zoom((self.zoom * ZOOM_STEP).min(MAX_ZOOM));
+        self.zoom
+    }
+
+    pub fn zoom_out(&mut self) -> f64 {
+        self.set_zoom((self.zoom / ZOOM_STEP).max(MIN_ZOOM));
+        self.zoom
+    }
+
+    pub fn zoom_reset(&mut self) -> f64 {
+        self.set_zoom(1.0);
+        self.zoom
+    }
+
+    fn set_zoom(&mut self, zoom: f64) {
+        self.zoom = zoom;
+
+        for window in self.entries.values_mut() {
+            window.state.set_zoom(&window.raw, zoom);
+        }
+    }
+
     pub fn remove(&mut self, id: Id) -> Option<Window<P, C>> {
-        let window = self.entries.remove(&id)?;
+        let mut window = self.entries.remove(&id)?;
         let _ = self.aliases.remove(&window.raw.id());
 
+        for (_, _, activation) in window.pending_activations.drain(..) {
+            activation.finish(None);
+        }
+
         Some(window)
     }
 }
@@ -135,9 +186,38 @@ where
     pub state: State<P>,
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
+    pub hide_on_close_request: bool,
     pub mouse_interaction: mouse::Interaction,
-    pub surface: C::Surface,
+    pub surface: Option<C::Surface>,
     pub renderer: P::Renderer,
+    pub recorder: Option<automation::Recorder>,
+    input_region: Option<Vec<Rectangle>>,
+    pass_through: Vec<Rectangle>,
+    pending_activations: Vec<(AsyncRequestSerial, Instant, PendingActivation)>,
+}
+
+enum PendingActivation {
+    Spawn(Command, oneshot::Sender<io::Result<Child>>),
+    Token(oneshot::Sender<Option<String>>),
+}
+
+impl PendingActivation {
+    fn finish(self, token: Option<String>) {
+        match self {
+            PendingActivation::Spawn(mut command, channel) => {
+                if let Some(token) = &token {
+                    let _ = command
+                        .env("XDG_ACTIVATION_TOKEN", token)
+                        .env("DESKTOP_STARTUP_ID", token);
+                }
+
+                let _ = channel.send(command.spawn());
+            }
+            PendingActivation::Token(channel) => {
+                let _ = channel.send(token);
+            }
+        }
+    }
 }
 
 impl<P, C> Window<P, C>
@@ -162,4 +242,282 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    pub fn set_input_region(&mut self, region: Option<Vec<Rectangle>>) {
+        self.input_region = region;
+        self.update_cursor_hittest();
+    }
+
+    /// Collects the pass-through regions of the given user interface.
+    ///
+    /// It must be called whenever the user interface of the window is
+    /// rebuilt, laid out again or scrolled.
+    pub fn update_pass_through(
+        &mut self,
+        ui: &mut UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+    ) {
+        let mut pass_through = operation::pass_through();
+
+        ui.operate(
+            &self.renderer,
+            &mut operation::black_box(&mut pass_through),
+        );
+
+        let operation::Outcome::Some(pass_through) = pass_through.finish()
+        else {
+            return;
+        };
+
+        if self.pass_through == pass_through {
+            return;
+        }
+
+        self.pass_through = pass_through;
+        self.update_cursor_hittest();
+    }
+
+    fn update_cursor_hittest(&self) {
+        let bounds = Rectangle::with_size(self.state.logical_size());
+
+        // Only a window that takes no input at all can be expressed
+        // natively, by letting all the mouse events pass through it
+        let is_passing_through = matches!(&self.input_region, Some(region) if region.is_empty())
+            || self
+                .pass_through
+                .iter()
+                .any(|region| region.intersection(&bounds) == Some(bounds));
+
+        let _ = self.raw.set_cursor_hittest(!is_passing_through);
+    }
+
+    /// Returns whether the given event starts an interaction inside the input
+    /// region of the window.
+    pub fn is_within_input_region(&self, event: &core::Event) -> bool {
+        if self.input_region.is_none() && self.pass_through.is_empty() {
+            return true;
+        }
+
+        let position = match event {
+            core::Event::Mouse(
+                mouse::Event::ButtonPressed(_)
+                | mouse::Event::WheelScrolled { .. },
+            ) => {
+                let Some(position) = self.state.cursor().position() else {
+                    return true;
+                };
+
+                position
+            }
+            core::Event::Touch(touch::Event::FingerPressed {
+                position,
+                ..
+            }) => *position,
+            _ => return true,
+        };
+
+        let is_within_input_region = match &self.input_region {
+            Some(region) => {
+                region.iter().any(|bounds| bounds.contains(position))
+            }
+            None => true,
+        };
+
+        is_within_input_region
+            && !self
+                .pass_through
+                .iter()
+                .any(|bounds| bounds.contains(position))
+    }
+
+    pub fn geometry(&self) -> Geometry {
+        let position = self
+            .raw
+            .outer_position()
+            .ok()
+            .map(|position| position.to_logical(self.raw.scale_factor()))
+            .map(|position| Point {
+                x: position.x,
+                y: position.y,
+            });
+
+        Geometry {
+            size: self.size(),
+            position,
+            maximized: self.raw.is_maximized(),
+            fullscreen: self.raw.fullscreen().is_some(),
+            monitor: self
+                .raw
+                .current_monitor()
+                .and_then(|monitor| monitor.name()),
+        }
+    }
+
+    pub fn set_geometry(&mut self, geometry: Geometry) {
+        let monitor = geometry.monitor.as_deref().and_then(|name| {
+            self.raw
+                .available_monitors()
+                .find(|monitor| monitor.name().as_deref() == Some(name))
+        });
+
+        // The geometry is applied to the restored window, since
+        // maximized and fullscreen windows ignore size and position changes
+        self.raw.set_fullscreen(None);
+        self.raw.set_maximized(false);
+
+        let _ = self.raw.request_inner_size(winit::dpi::LogicalSize {
+            width: geometry.size.width,
+            height: geometry.size.height,
+        });
+
+        if let Some(position) = geometry.position {
+            self.raw.set_outer_position(winit::dpi::LogicalPosition {
+                x: position.x,
+                y: position.y,
+            });
+        } else if let Some(monitor) = &monitor {
+            self.raw.set_outer_position(monitor.position());
+        }
+
+        self.raw.set_maximized(geometry.maximized);
+
+        if geometry.fullscreen {
+            self.raw.set_fullscreen(Some(
+                winit::window::Fullscreen::Borderless(
+                    monitor.or_else(|| self.raw.current_monitor()),
+                ),
+            ));
+        }
+    }
+
+    pub fn spawn_with_activation_token(
+        &mut self,
+        mut command: Command,
+        channel: oneshot::Sender<io::Result<Child>>,
+    ) {
+        if let Some(serial) = self.request_activation() {
+            self.pending_activations.push((
+                serial,
+                Instant::now(),
+                PendingActivation::Spawn(command, channel),
+            ));
+        } else {
+            let _ = channel.send(command.spawn());
+        }
+    }
+
+    pub fn request_activation_token(
+        &mut self,
+        channel: oneshot::Sender<Option<String>>,
+    ) {
+        if let Some(serial) = self.request_activation() {
+            self.pending_activations.push((
+                serial,
+                Instant::now(),
+                PendingActivation::Token(channel),
+            ));
+        } else {
+            let _ = channel.send(None);
+        }
+    }
+
+    fn request_activation(&self) -> Option<AsyncRequestSerial> {
+        #[cfg(all(
+            any(feature = "x11", feature = "wayland"),
+            unix,
+            not(target_vendor = "apple"),
+            not(target_os = "android"),
+            not(target_os = "redox"),
+        ))]
+        {
+            use winit::platform::startup_notify::WindowExtStartupNotify;
+
+            self.raw.request_activation_token().ok()
+        }
+
+        #[cfg(not(all(
+            any(feature = "x11", feature = "wayland"),
+            unix,
+            not(target_vendor = "apple"),
+            not(target_os = "android"),
+            not(target_os = "redox"),
+        )))]
+        {
+            None
+        }
+    }
+
+    pub fn finish_activation(
+        &mut self,
+        serial: AsyncRequestSerial,
+        token: ActivationToken,
+    ) {
+        let Some(index) = self
+            .pending_activations
+            .iter()
+            .position(|(pending, _, _)| *pending == serial)
+        else {
+            return;
+        };
+
+        let (_serial, _requested_at, activation) =
+            self.pending_activations.remove(index);
+
+        #[cfg(all(
+            any(feature = "x11", feature = "wayland"),
+            unix,
+            not(target_vendor = "apple"),
+            not(target_os = "android"),
+            not(target_os = "redox"),
+        ))]
+        {
+            use winit::platform::startup_notify;
+
+            // The raw token is only exposed through the environment, so we
+            // read it back right away and pass it to any child explicitly
+            startup_notify::set_activation_token_env(token);
+            let token = std::env::var("XDG_ACTIVATION_TOKEN").ok();
+            startup_notify::reset_activation_token_env();
+
+            activation.finish(token);
+        }
+
+        #[cfg(not(all(
+            any(feature = "x11", feature = "wayland"),
+            unix,
+            not(target_vendor = "apple"),
+            not(target_os = "android"),
+            not(target_os = "redox"),
+        )))]
+        {
+            let _ = token;
+
+            activation.finish(None);
+        }
+    }
+
+    /// Finishes the pending activations that the platform has not answered
+    /// in time without a token.
+    ///
+    /// A compositor may refuse to provide a token, in which case it never
+    /// notifies us at all.
+    pub fn expire_activations(&mut self) {
+        if self.pending_activations.is_empty() {
+            return;
+        }
+
+        let now = Instant::now();
+
+        let (expired, pending): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.pending_activations)
+                .into_iter()
+                .partition(|(_, requested_at, _)| {
+                    now.duration_since(*requested_at) >= ACTIVATION_TIMEOUT
+                });
+
+        self.pending_activations = pending;
+
+        for (_, _, activation) in expired {
+            activation.finish(None);
+        }
+    }
 }
@@ -1,4 +1,7 @@
 //! Configure your application.
+use crate::core::keyboard;
+use crate::core::mouse;
+
 use std::borrow::Cow;
 
 /// The settings of an application.
@@ -12,4 +15,285 @@ pub struct Settings {
 
     /// The fonts to load on boot.
     pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// The [`Scrolling`] behavior of the application.
+    pub scrolling: Scrolling,
+
+    /// Whether to suppress the key presses synthesized by the system while a
+    /// key is held down.
+    ///
+    /// This can be useful for hotkeys that should only trigger once per
+    /// press, like in games.
+    ///
+    /// By default, it is disabled.
+    pub suppress_key_repeats: bool,
+
+    /// Whether animations should be skipped in favor of instant transitions.
+    ///
+    /// This can be used to honor the accessibility preferences of the user.
+    ///
+    /// By default, it is disabled.
+    pub reduced_motion: bool,
+}
+
+/// The scrolling behavior of an application.
+///
+/// It is applied to every [`mouse::Event::WheelScrolled`] before it reaches
+/// any widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scrolling {
+    /// The amount of lines scrolled for every tick of the mouse wheel.
+    ///
+    /// The default value is `1.0`.
+    pub lines_per_tick: f32,
+
+    /// Whether to invert the direction of scrolling; also known as
+    /// "natural scrolling".
+    ///
+    /// The inversion is applied on top of the deltas reported by the
+    /// system. If the system is already configured for natural scrolling,
+    /// enabling this will restore the traditional direction.
+    ///
+    /// By default, it is disabled.
+    pub natural: bool,
+
+    /// Whether vertical scrolling with the mouse wheel should become
+    /// horizontal scrolling while Shift is pressed.
+    ///
+    /// Widgets do not handle Shift themselves; this is the only place where
+    /// the axes are swapped. Deltas that are already horizontal, like the
+    /// ones macOS produces while Shift is pressed, are left untouched.
+    ///
+    /// By default, it is enabled.
+    pub horizontal_with_shift: bool,
+}
+
+impl Scrolling {
+    /// Applies the [`Scrolling`] behavior to the given [`mouse::ScrollDelta`].
+    pub fn apply(
+        self,
+        delta: mouse::ScrollDelta,
+        modifiers: keyboard::Modifiers,
+    ) -> mouse::ScrollDelta {
+        let direction = if self.natural { -1.0 } else { 1.0 };
+
+        match delta {
+            mouse::ScrollDelta::Lines { x, y } => {
+                let (x, y) = if self.horizontal_with_shift
+                    && modifiers.shift()
+                    && x == 0.0
+                {
+                    (y, 0.0)
+                } else {
+                    (x, y)
+                };
+
+                mouse::ScrollDelta::Lines {
+                    x: x * self.lines_per_tick * direction,
+                    y: y * self.lines_per_tick * direction,
+                }
+            }
+            mouse::ScrollDelta::Pixels { x, y } => mouse::ScrollDelta::Pixels {
+                x: x * direction,
+                y: y * direction,
+            },
+        }
+    }
+}
+
+impl Default for Scrolling {
+    fn default() -> Self {
+        Self {
+            lines_per_tick: 1.0,
+            natural: false,
+            horizontal_with_shift: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::keyboard::Modifiers;
+    use crate::core::mouse::ScrollDelta;
+
+    #[test]
+    fn default_is_identity() {
+        let scrolling = Scrolling {
+            horizontal_with_shift: false,
+            ..Scrolling::default()
+        };
+
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Lines { x: 1.0, y: -2.0 },
+                Modifiers::SHIFT
+            ),
+            ScrollDelta::Lines { x: 1.0, y: -2.0 }
+        );
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Pixels { x: 3.0, y: 4.0 },
+                Modifiers::empty()
+            ),
+            ScrollDelta::Pixels { x: 3.0, y: 4.0 }
+        );
+    }
+
+    #[test]
+    fn lines_per_tick_only_scales_lines() {
+        let scrolling = Scrolling {
+            lines_per_tick: 3.0,
+            ..Scrolling::default()
+        };
+
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Lines { x: 1.0, y: -1.0 },
+                Modifiers::empty()
+            ),
+            ScrollDelta::Lines { x: 3.0, y: -3.0 }
+        );
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Pixels { x: 1.0, y: -1.0 },
+                Modifiers::empty()
+            ),
+            ScrollDelta::Pixels { x: 1.0, y: -1.0 }
+        );
+    }
+
+    #[test]
+    fn natural_inverts_both_kinds_of_delta() {
+        let scrolling = Scrolling {
+            lines_per_tick: 2.0,
+            natural: true,
+            ..Scrolling::default()
+        };
+
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Lines { x: 1.0, y: -1.0 },
+                Modifiers::empty()
+            ),
+            ScrollDelta::Lines { x: -2.0, y: 2.0 }
+        );
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Pixels { x: 5.0, y: -5.0 },
+                Modifiers::empty()
+            ),
+            ScrollDelta::Pixels { x: -5.0, y: 5.0 }
+        );
+    }
+
+    #[test]
+    fn horizontal_with_shift() {
+        let scrolling = Scrolling::default();
+
+        // Only vertical line deltas are swapped, and only with Shift pressed
+        assert_eq!(
+            scrolling
+                .apply(ScrollDelta::Lines { x: 0.0, y: 1.0 }, Modifiers::SHIFT),
+            ScrollDelta::Lines { x: 1.0, y: 0.0 }
+        );
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Lines { x: 0.0, y: 1.0 },
+                Modifiers::empty()
+            ),
+            ScrollDelta::Lines { x: 0.0, y: 1.0 }
+        );
+        assert_eq!(
+            scrolling
+                .apply(ScrollDelta::Lines { x: 2.0, y: 1.0 }, Modifiers::SHIFT),
+            ScrollDelta::Lines { x: 2.0, y: 1.0 }
+        );
+        assert_eq!(
+            scrolling.apply(
+                ScrollDelta::Pixels { x: 0.0, y: 1.0 },
+                Modifiers::SHIFT
+            ),
+            ScrollDelta::Pixels { x: 0.0, y: 1.0 }
+        );
+    }
+
+    #[test]
+    fn all_options_combined() {
+        let scrolling = Scrolling {
+            lines_per_tick: 3.0,
+            natural: true,
+            horizontal_with_shift: true,
+        };
+
+        assert_eq!(
+            scrolling
+                .apply(ScrollDelta::Lines { x: 0.0, y: 1.0 }, Modifiers::SHIFT),
+            ScrollDelta::Lines { x: -3.0, y: 0.0 }
+        );
+    }
+
+    #[test]
+    fn shift_scrolls_a_scrollable_horizontally() {
+        use crate::core::{Event, Length, Point, Size, Theme};
+        use crate::runtime::user_interface::{self, UserInterface};
+
+        use iced_widget::scrollable::{self, Direction, Scrollbar};
+        use iced_widget::Space;
+
+        fn offset(direction: Direction, modifiers: Modifiers) -> (f32, f32) {
+            let content =
+                Space::new(Length::Fixed(1000.0), Length::Fixed(1000.0));
+
+            let scrollable: scrollable::Scrollable<'_, _, Theme, ()> =
+                scrollable::Scrollable::new(content)
+                    .direction(direction)
+                    .on_scroll(|viewport| {
+                        let offset = viewport.absolute_offset();
+
+                        (offset.x, offset.y)
+                    });
+
+            let mut ui = UserInterface::build(
+                scrollable,
+                Size::new(100.0, 100.0),
+                user_interface::Cache::default(),
+                &mut (),
+            );
+
+            let delta = Scrolling::default()
+                .apply(ScrollDelta::Lines { x: 0.0, y: -1.0 }, modifiers);
+
+            let mut messages = Vec::new();
+
+            let _ = ui.update(
+                &[
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(
+                        modifiers,
+                    )),
+                    Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                ],
+                mouse::Cursor::Available(Point::new(50.0, 50.0)),
+                &mut (),
+                &mut crate::core::clipboard::Null,
+                &mut messages,
+            );
+
+            messages.pop().unwrap_or_default()
+        }
+
+        let both = Direction::Both {
+            vertical: Scrollbar::default(),
+            horizontal: Scrollbar::default(),
+        };
+
+        assert_eq!(offset(both, Modifiers::empty()), (0.0, 60.0));
+        assert_eq!(offset(both, Modifiers::SHIFT), (60.0, 0.0));
+
+        let horizontal = Direction::Horizontal(Scrollbar::default());
+
+        assert_eq!(offset(horizontal, Modifiers::empty()), (60.0, 0.0));
+        assert_eq!(offset(horizontal, Modifiers::SHIFT), (60.0, 0.0));
+    }
 }
@@ -12,6 +12,7 @@ pub fn fetch_information() -> Task<Information> {
 
 pub(crate) fn information(
     graphics_info: compositor::Information,
+    session_type: Option<String>,
 ) -> Information {
     use sysinfo::{Process, System};
     let mut system = System::new_all();
@@ -35,5 +36,28 @@ pub(crate) fn information(
         memory_used,
         graphics_adapter: graphics_info.adapter,
         graphics_backend: graphics_info.backend,
+        session_type: session_type
+            .or_else(|| std::env::var("XDG_SESSION_TYPE").ok()),
+        compositor: std::env::var("XDG_CURRENT_DESKTOP").ok(),
     }
 }
+
+pub(crate) fn session_type(window: &winit::window::Window) -> Option<String> {
+    use winit::raw_window_handle::{HasDisplayHandle, RawDisplayHandle};
+
+    let session_type = match window.display_handle().ok()?.as_raw() {
+        RawDisplayHandle::Wayland(_) => "wayland",
+        RawDisplayHandle::Xlib(_) | RawDisplayHandle::Xcb(_) => "x11",
+        RawDisplayHandle::Windows(_) => "windows",
+        RawDisplayHandle::AppKit(_) => "macos",
+        RawDisplayHandle::UiKit(_) => "ios",
+        RawDisplayHandle::Android(_) => "android",
+        RawDisplayHandle::Web(_) => "web",
+        RawDisplayHandle::Orbital(_) => "orbital",
+        RawDisplayHandle::Haiku(_) => "haiku",
+        RawDisplayHandle::Drm(_) | RawDisplayHandle::Gbm(_) => "drm",
+        _ => return None,
+    };
+
+    Some(session_type.to_owned())
+}